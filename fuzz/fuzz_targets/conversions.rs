@@ -0,0 +1,87 @@
+//! Checks bool8/bool32 conversions and operators against a simple reference model: nonzero == true.
+
+#![no_main]
+use abibool::*;
+use libfuzzer_sys::fuzz_target;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash(value: impl Hash) -> u64 { let mut h = DefaultHasher::new(); value.hash(&mut h); h.finish() }
+
+fn check_bool8(a: u8, b: u8) {
+    let (ra, rb) = (a != 0, b != 0);
+    let (x, y) = (bool8::from(a), bool8::from(b));
+
+    assert_eq!(bool::from(x), ra);
+    assert_eq!(u8::from(x), a);
+    assert_eq!(x == y, ra == rb);
+    assert_eq!(x == ra, true);
+    assert_eq!(x.cmp(&y), ra.cmp(&rb));
+    assert_eq!(!x, !ra);
+    assert_eq!(u8::from(x & y), (ra & rb) as u8);
+    assert_eq!(u8::from(x | y), (ra | rb) as u8);
+    assert_eq!(u8::from(x ^ y), (ra ^ rb) as u8);
+    assert_eq!(i32::from(x | bool32::from(b as i32)), (ra | rb) as i32);
+    let mut z = x; z |= rb; assert_eq!(u8::from(z), (ra | rb) as u8);
+    assert_eq!(x.to_bool_checked(), (ra, a <= 1));
+    assert_eq!(x.as_bool32() == x, true);
+    assert_eq!(i32::from(x.as_bool32()), ra as i32);
+    assert_eq!(x.stable_key(), if ra { "true" } else { "false" });
+    if ra == rb { assert_eq!(hash(x), hash(y)); }
+    assert_eq!(hash(x), hash(ra));
+}
+
+fn check_bool32(a: i32, b: i32) {
+    let (ra, rb) = (a != 0, b != 0);
+    let (x, y) = (bool32::from(a), bool32::from(b));
+
+    assert_eq!(bool::from(x), ra);
+    assert_eq!(i32::from(x), a);
+    assert_eq!(x == y, ra == rb);
+    assert_eq!(x == ra, true);
+    assert_eq!(x.cmp(&y), ra.cmp(&rb));
+    assert_eq!(!x, !ra);
+    assert_eq!(i32::from(x & y), (ra & rb) as i32);
+    assert_eq!(i32::from(x | y), (ra | rb) as i32);
+    assert_eq!(i32::from(x ^ y), (ra ^ rb) as i32);
+    let mut z = x; z &= rb; assert_eq!(i32::from(z), (ra & rb) as i32);
+    assert_eq!(x.to_bool_checked(), (ra, a == 0 || a == 1));
+    assert_eq!(x.as_u8(), ra as u8);
+    assert_eq!(u8::from(x.as_bool8()), ra as u8);
+    assert_eq!(bool32::from_network(x.to_network()), x);
+    assert_eq!(i32::from_be_bytes(x.to_network()), ra as i32);
+    assert_eq!(x.ok_if_true().is_ok(), ra);
+    if ra == rb { assert_eq!(hash(x), hash(y)); }
+    assert_eq!(hash(x), hash(ra));
+}
+
+/// Every cross-width `PartialEq`/`PartialOrd` pair must agree with `bool`'s, so a typo in one of the hand-written impls can't invert an ordering.
+fn check_cross_width(a: u8, b: u8) {
+    let (ra, rb) = (a != 0, b != 0);
+    let xs = (bool8::from(a), bool16::from(a as u16 * 0x0101), bool32::from(a as i32 * -1), bool64::from((a as u64) << 56));
+    let ys = (bool8::from(b), bool16::from(b as u16), bool32::from(b as i32), bool64::from(b as u64));
+    let expected = Some(ra.cmp(&rb));
+
+    macro_rules! check { ($($x:expr),* ; $ys:expr) => {$(
+        assert_eq!($x.partial_cmp(&$ys.0), expected);
+        assert_eq!($x.partial_cmp(&$ys.1), expected);
+        assert_eq!($x.partial_cmp(&$ys.2), expected);
+        assert_eq!($x.partial_cmp(&$ys.3), expected);
+        assert_eq!($x.partial_cmp(&rb), expected);
+        assert_eq!(ra.partial_cmp(&$x), Some(ra.cmp(&ra)));
+        assert_eq!($x == $ys.0 && $x == $ys.1 && $x == $ys.2 && $x == $ys.3, ra == rb);
+    )*}}
+    check!(xs.0, xs.1, xs.2, xs.3 ; ys);
+}
+
+fuzz_target!(|data: &[u8]| {
+    for pair in data.chunks_exact(2) {
+        check_bool8(pair[0], pair[1]);
+        check_cross_width(pair[0], pair[1]);
+    }
+    for pair in data.chunks_exact(8) {
+        let a = i32::from_ne_bytes([pair[0], pair[1], pair[2], pair[3]]);
+        let b = i32::from_ne_bytes([pair[4], pair[5], pair[6], pair[7]]);
+        check_bool32(a, b);
+    }
+});
@@ -1,12 +1,16 @@
 #![doc = include_str!("../Readme.md")]
 #![no_std]
 
-use core::borrow::Borrow;
+#[cfg(feature = "alloc")] extern crate alloc;
+
 use core::cmp::Ordering;
 use core::fmt::{self, Debug, Display, Formatter};
-use core::hash::{Hash, Hasher};
+use core::hash::Hash;
 use core::mem::transmute;
-use core::ops::{Deref, DerefMut, Not};
+use core::ops::{AddAssign, BitAnd, BitOr, BitXor, Deref, DerefMut, Not};
+use core::sync::atomic;
+#[cfg(target_has_atomic = "8" )] use core::sync::atomic::AtomicU8;
+#[cfg(target_has_atomic = "32")] use core::sync::atomic::AtomicI32;
 
 // XXX: REMOVEME: Get rid of these defs in the next breaking revision of abibool.
 // They're too winapi specific.  See other "XXX: REMOVEME: " comments for thoughts.
@@ -21,21 +25,110 @@ use u8 as BOOLEAN;  // use winapi::shared::minwindef::BOOLEAN;
 /// [bool8] can be used in these cases to avoid the need for internal allocations or conversions for mere ABI conversions.
 ///
 /// `0` is `false`y, all other bit patterns are `true`thy.
+/// Comparisons and hashes are based on truthiness - this includes element-wise comparisons of slices and arrays of [bool8].
+/// Wrap values in [ByRaw] to compare and hash bit patterns instead.
+///
+/// ```
+/// # use abibool::*;
+/// let raw = bool8::from_raw(0xff);            // e.g. written by C
+/// assert_eq!(raw, true);                      // truthiness based...
+/// assert_eq!(raw, bool8::TRUE);
+/// assert_eq!(raw.into_raw(), 0xff);           // ...until you ask for the bits
+/// assert_eq!(raw.normalized().into_raw(), 1);
+/// ```
 ///
 /// [BOOLEAN]:      https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types#BOOLEAN
 #[allow(non_camel_case_types)] // Okay, `bool8` is kind of a weird type name I agree... warranted in this case though IMO
 #[derive(Clone, Copy)]
-#[repr(transparent)] pub struct bool8(BOOLEAN);
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout, zerocopy::Unaligned))] // zerocopy doesn't support manual impls
+#[repr(transparent)] pub struct bool8(u8);  // not `BOOLEAN`: cbindgen doesn't resolve `use` aliases, and should emit `typedef uint8_t bool8;`
 pub use bool8 as b8;
 
 impl bool8 {
-    /// bool8(`0`)
-    pub const FALSE : bool8 = bool8(0);
+    // The slice and array casts below are sound: bool8 is #[repr(transparent)] over u8, and every bit pattern is valid for both.
+
+    /// Reinterpret a `&[u8]` as a `&[bool8]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// let flags = bool8::from_u8_slice(&[0, 1, 0xff]);
+    /// assert_eq!(flags, [false, true, true]);
+    /// ```
+    pub fn from_u8_slice(slice: &[u8]) -> &[bool8] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [u8]` as a `&mut [bool8]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// let mut raw = [0u8, 0xff];
+    /// bool8::from_u8_slice_mut(&mut raw)[1].normalize();
+    /// assert_eq!(raw, [0, 1]);
+    /// ```
+    pub fn from_u8_slice_mut(slice: &mut [u8]) -> &mut [bool8] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&[bool8]` as a `&[u8]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// assert_eq!(bool8::as_u8_slice(&[bool8::TRUE, bool8::from_raw(0xff)]), [1, 0xff]);
+    /// ```
+    pub fn as_u8_slice(slice: &[bool8]) -> &[u8] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [bool8]` as a `&mut [u8]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// let mut flags = [bool8::FALSE; 2];
+    /// bool8::as_u8_slice_mut(&mut flags)[0] = 0x80; // e.g. filled by a byte-oriented read
+    /// assert_eq!(flags, [true, false]);
+    /// ```
+    pub fn as_u8_slice_mut(slice: &mut [bool8]) -> &mut [u8] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// Convert a `[u8; N]` (e.g. a C `BOOLEAN caps[N]` field) to a `[bool8; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// let caps = bool8::from_u8_array([1, 0, 0xff, 0, 0, 0, 0, 2]);
+    /// assert_eq!(caps, [true, false, true, false, false, false, false, true]);
+    /// assert_eq!(bool8::from_u8_array([]), [false; 0]);
+    /// ```
+    pub fn from_u8_array<const N: usize>(array: [u8; N]) -> [bool8; N] { array.map(bool8) }
+
+    /// Convert a `[bool8; N]` to a `[u8; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// assert_eq!(bool8::into_u8_array([bool8::TRUE, bool8::from_raw(0xff)]), [1, 0xff]);
+    /// ```
+    pub fn into_u8_array<const N: usize>(array: [bool8; N]) -> [u8; N] { array.map(|b| b.0) }
+
+    /// Reinterpret a `&[u8; N]` as a `&[bool8; N]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// assert_eq!(bool8::from_u8_array_ref(&[0, 2]), &[false, true]);
+    /// ```
+    pub fn from_u8_array_ref<const N: usize>(array: &[u8; N]) -> &[bool8; N] { unsafe { &*array.as_ptr().cast::<[bool8; N]>() } }
 
-    /// bool8(`1`)
-    pub const TRUE  : bool8 = bool8(1);
+    /// Reinterpret a `&[bool8; N]` as a `&[u8; N]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// assert_eq!(bool8::as_u8_array(&[bool8::from_raw(2); 3]), &[2; 3]);
+    /// assert_eq!(bool8::as_u8_array::<0>(&[]), &[0u8; 0]);
+    /// ```
+    pub fn as_u8_array<const N: usize>(array: &[bool8; N]) -> &[u8; N] { unsafe { &*array.as_ptr().cast::<[u8; N]>() } }
 
-    pub fn from(value: impl Into<Self>) -> Self { value.into() }
+    /// `true` if `slice` has exactly the raw values of `bytes` (not just the same truthiness) - e.g. for tests pinning FFI byte layouts.
+    ///
+    /// ```
+    /// # use abibool::bool8;
+    /// let flags = [bool8::TRUE, bool8::from_raw(0xff), bool8::FALSE];
+    /// assert!( bool8::raw_eq_bytes(&flags, &[1, 0xff, 0]));
+    /// assert!(!bool8::raw_eq_bytes(&flags, &[1, 1, 0]));
+    /// assert!(!bool8::raw_eq_bytes(&flags, &[1, 0xff]));
+    /// ```
+    pub fn raw_eq_bytes(slice: &[bool8], bytes: &[u8]) -> bool { Self::as_u8_slice(slice) == bytes }
 }
 
 /// 32-bit boolean type that's ABI-compatible with Win32's [BOOL].
@@ -45,30 +138,1412 @@ impl bool8 {
 /// [bool32] can be used in these cases to avoid the need for internal allocations or conversions for mere ABI conversions.
 ///
 /// `0` is `false`y, all other bit patterns are `true`thy.
+/// Comparisons and hashes are based on truthiness - this includes element-wise comparisons of slices and arrays of [bool32].
+/// Wrap values in [ByRaw] to compare and hash bit patterns instead.
+///
+/// ```
+/// # use abibool::*;
+/// let flag = bool32::from_raw(-1);
+/// assert_eq!(flag, true);                     // truthiness
+/// assert_eq!(flag, bool8::TRUE);
+/// assert_eq!(flag.into_raw(), -1);            // bit pattern
+/// ```
 ///
 /// [BOOL]:         https://docs.microsoft.com/en-us/windows/win32/winprog/windows-data-types#BOOL
 #[allow(non_camel_case_types)] // Okay, `bool32` is kind of a weird type name I agree... warranted in this case though IMO
 #[derive(Clone, Copy)]
-#[repr(transparent)] pub struct bool32(BOOL);
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))] // zerocopy doesn't support manual impls.  Not `Unaligned`: `i32` is 4-byte aligned - see `bool8` or `[u8; 4]` for packed structs.
+#[repr(transparent)] pub struct bool32(i32); // not `BOOL`: cbindgen doesn't resolve `use` aliases, and should emit `typedef int32_t bool32;`
 pub use bool32 as b32;
 
 impl bool32 {
-    /// bool32(`0`)
-    pub const FALSE : bool32 = bool32(0);
+    // The slice and array casts below are sound: bool32 is #[repr(transparent)] over i32, which shares its size and alignment with u32, and every bit pattern is valid for all three.
+
+    /// Reinterpret a `&[i32]` as a `&[bool32]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_i32_slice(&[0, -1]), [false, true]);
+    /// ```
+    pub fn from_i32_slice(slice: &[i32]) -> &[bool32] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [i32]` as a `&mut [bool32]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// let mut raw = [-1, 0];
+    /// bool32::from_i32_slice_mut(&mut raw)[0].normalize();
+    /// assert_eq!(raw, [1, 0]);
+    /// ```
+    pub fn from_i32_slice_mut(slice: &mut [i32]) -> &mut [bool32] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&[bool32]` as a `&[i32]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::as_i32_slice(&[bool32::from_raw(-1), bool32::FALSE]), [-1, 0]);
+    /// ```
+    pub fn as_i32_slice(slice: &[bool32]) -> &[i32] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [bool32]` as a `&mut [i32]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// let mut flags = [bool32::FALSE; 2];
+    /// bool32::as_i32_slice_mut(&mut flags)[1] = -1; // e.g. filled by an FFI call taking `BOOL*`
+    /// assert_eq!(flags, [false, true]);
+    /// ```
+    pub fn as_i32_slice_mut(slice: &mut [bool32]) -> &mut [i32] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// `true` if `slice` has exactly the raw values of `values` (not just the same truthiness) - see [bool8::raw_eq_bytes].
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// let flags = [bool32::from_raw(-1), bool32::FALSE];
+    /// assert!( bool32::raw_eq_i32s(&flags, &[-1, 0]));
+    /// assert!(!bool32::raw_eq_i32s(&flags, &[1, 0]));
+    /// ```
+    pub fn raw_eq_i32s(slice: &[bool32], values: &[i32]) -> bool { Self::as_i32_slice(slice) == values }
+
+    /// Reinterpret a `&[u32]` as a `&[bool32]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_u32_slice(&[0, u32::MAX]), [false, true]);
+    /// ```
+    pub fn from_u32_slice(slice: &[u32]) -> &[bool32] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [u32]` as a `&mut [bool32]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// let mut raw = [u32::MAX, 0];
+    /// bool32::from_u32_slice_mut(&mut raw)[1].toggle();
+    /// assert_eq!(raw, [u32::MAX, 1]);
+    /// ```
+    pub fn from_u32_slice_mut(slice: &mut [u32]) -> &mut [bool32] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&[bool32]` as a `&[u32]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::as_u32_slice(&[bool32::from_raw(-1)]), [u32::MAX]);
+    /// ```
+    pub fn as_u32_slice(slice: &[bool32]) -> &[u32] { unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) } }
+
+    /// Reinterpret a `&mut [bool32]` as a `&mut [u32]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// let mut flags = [bool32::TRUE];
+    /// bool32::as_u32_slice_mut(&mut flags)[0] = 0x8000_0000;
+    /// assert_eq!(flags[0].into_raw(), i32::MIN);
+    /// ```
+    pub fn as_u32_slice_mut(slice: &mut [bool32]) -> &mut [u32] { unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) } }
+
+    /// Convert a `[i32; N]` (e.g. a C `BOOL flags[N]` field) to a `[bool32; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_i32_array([0, -1, 2]), [false, true, true]);
+    /// ```
+    pub fn from_i32_array<const N: usize>(array: [i32; N]) -> [bool32; N] { array.map(bool32) }
+
+    /// Convert a `[bool32; N]` to a `[i32; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::into_i32_array([bool32::from_raw(-1), bool32::FALSE]), [-1, 0]);
+    /// ```
+    pub fn into_i32_array<const N: usize>(array: [bool32; N]) -> [i32; N] { array.map(|b| b.0) }
+
+    /// Reinterpret a `&[i32; N]` as a `&[bool32; N]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_i32_array_ref(&[-1, 0]), &[true, false]);
+    /// ```
+    pub fn from_i32_array_ref<const N: usize>(array: &[i32; N]) -> &[bool32; N] { unsafe { &*array.as_ptr().cast::<[bool32; N]>() } }
+
+    /// Reinterpret a `&[bool32; N]` as a `&[i32; N]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::as_i32_array(&[bool32::from_raw(-1); 2]), &[-1, -1]);
+    /// ```
+    pub fn as_i32_array<const N: usize>(array: &[bool32; N]) -> &[i32; N] { unsafe { &*array.as_ptr().cast::<[i32; N]>() } }
+
+    /// Convert a `[u32; N]` to a `[bool32; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_u32_array([u32::MAX])[0].into_raw(), -1);
+    /// ```
+    pub fn from_u32_array<const N: usize>(array: [u32; N]) -> [bool32; N] { array.map(|raw| bool32(raw as i32)) }
+
+    /// Convert a `[bool32; N]` to a `[u32; N]` as-is.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::into_u32_array([bool32::from_raw(-1), bool32::TRUE]), [u32::MAX, 1]);
+    /// ```
+    pub fn into_u32_array<const N: usize>(array: [bool32; N]) -> [u32; N] { array.map(|b| b.0 as u32) }
+
+    /// Reinterpret a `&[u32; N]` as a `&[bool32; N]` without copying.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_u32_array_ref(&[0, 0x100]), &[false, true]);
+    /// ```
+    pub fn from_u32_array_ref<const N: usize>(array: &[u32; N]) -> &[bool32; N] { unsafe { &*array.as_ptr().cast::<[bool32; N]>() } }
+
+    /// Reinterpret a `&[bool32; N]` as a `&[u32; N]` without copying.  Raw bit patterns are preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::as_u32_array(&[bool32::from_raw(-1)]), &[u32::MAX]);
+    /// ```
+    pub fn as_u32_array<const N: usize>(array: &[bool32; N]) -> &[u32; N] { unsafe { &*array.as_ptr().cast::<[u32; N]>() } }
+
+    /// Wrap a raw `u32` as-is, without normalizing - a `const` alternative to `From<u32>`.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// const ALL_BITS : bool32 = bool32::from_raw_u32(u32::MAX);
+    /// assert_eq!(ALL_BITS.into_raw(), -1);
+    /// ```
+    pub const fn from_raw_u32(raw: u32) -> Self { bool32(raw as i32) }
+
+    /// Unwrap to the raw bits as a `u32`, without normalizing - a `const` alternative to `From<bool32> for u32`.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// const RAW : u32 = bool32::from_raw(-1).into_raw_u32();
+    /// assert_eq!(RAW, u32::MAX);
+    /// ```
+    pub const fn into_raw_u32(self) -> u32 { self.0 as u32 }
+
+    /// `Ok(())` if truthy, `Err(())` otherwise - for C APIs that return a nonzero [BOOL](bool32) on success.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// # #[allow(non_snake_case)] fn SetThing(_: i32) -> bool32 { bool32::from_raw(-1) }
+    /// assert_eq!(SetThing(42).ok_if_true(), Ok(()));
+    /// assert_eq!(bool32::FALSE.ok_if_true(), Err(()));
+    /// ```
+    #[allow(clippy::result_unit_err)] // There's no more information to give - callers typically `.map_err(...)` to `GetLastError()` or similar
+    pub fn ok_if_true(self) -> Result<(), ()> { if self.0 != 0 { Ok(()) } else { Err(()) } }
+
+    /// Convert a Rust predicate's result for returning from a C callback.
+    ///
+    /// Many C callback contracts expect *exactly* `1` for true, not just any nonzero [BOOL](bool32) - this always yields `0` or `1`.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// extern "C" fn is_even(n: i32) -> bool32 { bool32::to_c_predicate_return(n % 2 == 0) }
+    /// assert_eq!(is_even(2).into_raw(), 1);
+    /// assert_eq!(is_even(3).into_raw(), 0);
+    /// ```
+    pub fn to_c_predicate_return(b: bool) -> bool32 { Self::from(b) }
+
+    /// `1` if truthy, `0` otherwise - the safe way to hand a [BOOL](bool32) to a [BOOLEAN](bool8) slot.
+    ///
+    /// This is based on truthiness of the whole word, *not* truncation: `bool32::from(0x100).as_u8()` is `1`, not `0`.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_raw(0x100).as_u8(), 1);
+    /// assert_eq!(bool32::FALSE.as_u8(), 0);
+    /// ```
+    pub fn as_u8(self) -> u8 { (self.0 != 0) as u8 }
+
+    /// Zero-extend a raw [BOOLEAN](bool8) byte as-is - truthiness (and the raw value) is preserved.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_low_byte(0xff).into_raw(), 0xff);
+    /// ```
+    pub const fn from_low_byte(byte: u8) -> Self { Self(byte as i32) }
+
+    /// The least significant byte of the raw value, by truncation - for code that only ever looks at the low byte.
+    ///
+    /// Unlike [as_u8](Self::as_u8), this can lose truthiness: `bool32::from_raw(0x100).low_byte()` is `0`.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_raw(0x1ff).low_byte(), 0xff);
+    /// assert_eq!(bool32::from_raw(0x100).low_byte(), 0);
+    /// ```
+    pub const fn low_byte(self) -> u8 { self.0 as u8 }
+
+    /// Big-endian ("network byte order") bytes of the canonical `0` or `1`, regardless of host endianness.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_raw(-1).to_network(), [0, 0, 0, 1]);
+    /// assert_eq!(bool32::FALSE.to_network(), [0, 0, 0, 0]);
+    /// ```
+    pub fn to_network(self) -> [u8; 4] { (bool::from(self) as i32).to_be_bytes() }
+
+    /// Read a big-endian ("network byte order") [bool32].  Any nonzero value is truthy.
+    ///
+    /// ```
+    /// # use abibool::bool32;
+    /// assert_eq!(bool32::from_network([0, 0, 0, 1]).into_raw(), 1);
+    /// assert_eq!(bool32::from_network([1, 0, 0, 0]).into_raw(), 0x0100_0000); // truthy, but not canonical
+    /// assert_eq!(bool32::from_network(bool32::from_raw(-1).to_network()).into_raw(), 1);
+    /// ```
+    pub fn from_network(bytes: [u8; 4]) -> bool32 { Self(i32::from_be_bytes(bytes)) }
+}
+
+
+/// 16-bit boolean type for C ABIs that use `int16_t`/`uint16_t` boolean fields.
+///
+/// 99% of the time, you should prefer [bool] in your interfaces and simply convert between types.
+/// However, some C SDKs declare 16-bit boolean fields in their structures, or take arrays of them.
+/// [bool16] can be used in these cases to avoid the need for internal allocations or conversions for mere ABI conversions.
+///
+/// `0` is `false`y, all other bit patterns are `true`thy.
+/// Comparisons and hashes are based on truthiness - this includes element-wise comparisons of slices and arrays of [bool16].
+/// Wrap values in [ByRaw] to compare and hash bit patterns instead.
+#[allow(non_camel_case_types)] // Okay, `bool16` is kind of a weird type name I agree... warranted in this case though IMO
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))] // zerocopy doesn't support manual impls.  Not `Unaligned`, like bool32.
+#[repr(transparent)] pub struct bool16(u16);
+pub use bool16 as b16;
+
+/// 64-bit boolean type for C ABIs that use `int64_t`/`uint64_t` boolean fields.
+///
+/// 99% of the time, you should prefer [bool] in your interfaces and simply convert between types.
+/// However, some C SDKs declare 64-bit boolean fields in their structures, or take arrays of them.
+/// [bool64] can be used in these cases to avoid the need for internal allocations or conversions for mere ABI conversions.
+///
+/// `0` is `false`y, all other bit patterns are `true`thy.
+/// Comparisons and hashes are based on truthiness - this includes element-wise comparisons of slices and arrays of [bool64].
+/// Wrap values in [ByRaw] to compare and hash bit patterns instead.
+#[allow(non_camel_case_types)] // Okay, `bool64` is kind of a weird type name I agree... warranted in this case though IMO
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "zerocopy", derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout))] // zerocopy doesn't support manual impls.  Not `Unaligned`, like bool32.
+#[repr(transparent)] pub struct bool64(u64);
+pub use bool64 as b64;
+
+
+// Implementation detail of define_abibool!: a doc example for this crate's own types, and nothing for types defined by users of the macro
+// (their doctests would run in *their* crate, with `abibool` possibly renamed, and rustdoc would show our types' names in their docs.)
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __abibool_example {
+    (bool8,  $($piece:expr),* $(,)?) => { concat!("```\n# use abibool::*;\n", $($piece,)* "\n```") };
+    (bool16, $($piece:expr),* $(,)?) => { concat!("```\n# use abibool::*;\n", $($piece,)* "\n```") };
+    (bool32, $($piece:expr),* $(,)?) => { concat!("```\n# use abibool::*;\n", $($piece,)* "\n```") };
+    (bool64, $($piece:expr),* $(,)?) => { concat!("```\n# use abibool::*;\n", $($piece,)* "\n```") };
+    ($other:ident, $($piece:expr),* $(,)?) => { "" };
+}
+
+/// Define a custom ABI boolean newtype over any integer type, with the same truthiness semantics as [bool8], [bool32], etc.
+///
+/// `0` is `false`y, all other bit patterns are `true`thy, and comparisons and hashes are based on truthiness.
+/// This generates the struct (`#[repr(transparent)]` over the integer) along with the common methods
+/// (`FALSE`/`TRUE`, `new`, `from_raw`/`into_raw`, `toggled`, `normalized`, etc.) and traits
+/// ([Default], [Debug], [Display], [FromStr](core::str::FromStr), [From], [Not](core::ops::Not), bitwise operators, [PartialEq], [Ord], [Hash], ...)
+/// that this crate's own types get - which are defined with it.
+///
+/// Operators and comparisons are provided against the type itself and [bool].
+/// Conversions to and from this crate's types go through [bool], or the `as_bool8`, `as_bool32`, etc. methods.
+///
+/// Every generated item is documented, so this works in crates that `#![deny(missing_docs)]`:
+///
+/// ```
+/// # #![deny(missing_docs)]
+/// # #![doc = "Example crate."]
+/// abibool::define_abibool! {
+///     /// Signed 8-bit boolean, for an SDK that declares its flags as `int8_t`.
+///     pub struct sbool8(i8);
+/// }
+///
+/// # fn main() {
+/// let flag = sbool8::from_raw(-1);
+/// assert_eq!(flag, true);
+/// assert_eq!(flag, sbool8::TRUE);
+/// assert_eq!(flag.normalized().into_raw(), 1);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_abibool {
+    ( $(#[$attr:meta])* $vis:vis struct $name:ident($int:ty); ) => {
+        $(#[$attr])*
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Copy)]
+        #[repr(transparent)] $vis struct $name($int);
+        $crate::define_abibool!(@impl $name($int));
+    };
+
+    // Implementation detail: the methods and traits for an existing `#[repr(transparent)] struct $name($int);`.
+    // Used directly for this crate's own types, so their struct definitions remain visible to e.g. cbindgen.
+    ( @impl $name:ident($int:ty) ) => {
+        impl $name {
+            #[doc = concat!(stringify!($name), "(`0`)")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::FALSE, false);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.into_raw(), 0);\n",
+                "assert_eq!(", stringify!($name), "::default(), ", stringify!($name), "::FALSE);",
+            )]
+            pub const FALSE : $name = $name(0);
+
+            #[doc = concat!(stringify!($name), "(`1`)")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::TRUE, true);\n",
+                "assert_eq!(", stringify!($name), "::TRUE.into_raw(), 1);",
+            )]
+            pub const TRUE  : $name = $name(1);
+
+            /// `TRUE` or `FALSE` - a `const` alternative to [`From<bool>`](From).
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const ENABLED : ", stringify!($name), " = ", stringify!($name), "::new(true);\n",
+                "assert_eq!(ENABLED.into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::new(false).into_raw(), 0);",
+            )]
+            pub const fn new(value: bool) -> Self { Self(value as $int) }
+
+            #[doc = concat!("Convert from a [bool] (canonically) or a raw `", stringify!($int), "` (as-is) - shorthand for [Into], without naming the type twice.")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from(true).into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::from(2).into_raw(), 2);",
+            )]
+            pub fn from(value: impl ::core::convert::Into<Self>) -> Self { value.into() }
+
+            /// Truthiness - a `const` alternative to [`bool::from`].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const ON : bool = ", stringify!($name), "::from_raw(2).to_bool();\n",
+                "assert!(ON);\n",
+                "assert!(!", stringify!($name), "::FALSE.to_bool());",
+            )]
+            pub const fn to_bool(self) -> bool { self.0 != 0 }
+
+            /// `true` if truthy (nonzero.)
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::from_raw(2).is_true());\n",
+                "assert!(!", stringify!($name), "::FALSE.is_true());",
+            )]
+            pub const fn is_true(self) -> bool { self.0 != 0 }
+
+            /// `true` if falsey (zero.)
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::FALSE.is_false());\n",
+                "assert!(!", stringify!($name), "::from_raw(2).is_false());",
+            )]
+            pub const fn is_false(self) -> bool { self.0 == 0 }
+
+            #[doc = concat!("The opposite canonical value - unlike [Not](core::ops::Not), which yields a [bool], this stays a [`", stringify!($name), "`](Self).")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).toggled().into_raw(), 0);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.toggled().into_raw(), 1);",
+            )]
+            pub const fn toggled(self) -> Self { Self((self.0 == 0) as $int) }
+
+            /// Flip in place to the opposite canonical value - e.g. for a field of a `#[repr(C)]` struct.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "#[repr(C)] struct Settings { enabled: ", stringify!($name), " }\n",
+                "let mut settings = Settings { enabled: ", stringify!($name), "::from_raw(2) };\n",
+                "settings.enabled.toggle();\n",
+                "assert_eq!(settings.enabled.into_raw(), 0);\n",
+                "settings.enabled.toggle();\n",
+                "assert_eq!(settings.enabled.into_raw(), 1);",
+            )]
+            pub fn toggle(&mut self) { *self = self.toggled() }
+
+            /// `FALSE` if zero, `TRUE` (`1`) otherwise - e.g. before hashing or byte-comparing raw struct images.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).normalized().into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.normalized().into_raw(), 0);",
+            )]
+            pub const fn normalized(self) -> Self { Self((self.0 != 0) as $int) }
+
+            /// Normalize in place to canonical `0` or `1`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut flag = ", stringify!($name), "::from_raw(0x40);\n",
+                "flag.normalize();\n",
+                "assert_eq!(flag.into_raw(), 1);",
+            )]
+            pub fn normalize(&mut self) { *self = self.normalized() }
+
+            /// Canonical `1` or `0` as a C `int` - e.g. for a callback declared to return `int` rather than `BOOL`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).to_c_bool_int(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.to_c_bool_int(), 0);",
+            )]
+            pub const fn to_c_bool_int(self) -> i32 { (self.0 != 0) as i32 }
+
+            /// Canonical logical AND - usable in `const` contexts, unlike [BitAnd](core::ops::BitAnd).
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const BOTH : ", stringify!($name), " = ", stringify!($name), "::from_raw(2).and_c(", stringify!($name), "::from_raw(0x40));\n",
+                "assert_eq!(BOTH.into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::TRUE.and_c(", stringify!($name), "::FALSE).into_raw(), 0);",
+            )]
+            pub const fn and_c(self, other: Self) -> Self { Self(((self.0 != 0) & (other.0 != 0)) as $int) }
+
+            /// Canonical logical OR - usable in `const` contexts, unlike [BitOr](core::ops::BitOr).
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const EITHER : ", stringify!($name), " = ", stringify!($name), "::FALSE.or_c(", stringify!($name), "::from_raw(2));\n",
+                "assert_eq!(EITHER.into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.or_c(", stringify!($name), "::FALSE).into_raw(), 0);",
+            )]
+            pub const fn or_c(self, other: Self) -> Self { Self(((self.0 != 0) | (other.0 != 0)) as $int) }
+
+            /// `f()` if truthy, otherwise `default` - like [Option::map_or].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).map_or(0, || 42), 42);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.map_or(0, || unreachable!()), 0);",
+            )]
+            pub fn map_or<T>(self, default: T, f: impl FnOnce() -> T) -> T { if self.0 != 0 { f() } else { default } }
+
+            /// `if_true` if truthy, otherwise `if_false`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).unwrap_or(\"on\", \"off\"), \"on\");\n",
+                "assert_eq!(", stringify!($name), "::FALSE.unwrap_or(\"on\", \"off\"), \"off\");",
+            )]
+            pub fn unwrap_or<T>(self, if_true: T, if_false: T) -> T { if self.0 != 0 { if_true } else { if_false } }
+
+            /// `Ok(())` if truthy, otherwise `Err(err)` - for C APIs that signal success with a boolean, like [Option::ok_or].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).ok_or(\"failed\"), Ok(()));\n",
+                "assert_eq!(", stringify!($name), "::FALSE.ok_or(\"failed\"), Err(\"failed\"));",
+            )]
+            pub fn ok_or<E>(self, err: E) -> ::core::result::Result<(), E> { if self.0 != 0 { Ok(()) } else { Err(err) } }
+
+            /// `Ok(())` if truthy, otherwise `Err(err())` - e.g. `.ok_or_else(|| io::Error::last_os_error())`, like [Option::ok_or_else].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::TRUE.ok_or_else(|| unreachable!()), Ok::<(), ()>(()));\n",
+                "assert_eq!(", stringify!($name), "::FALSE.ok_or_else(|| \"failed\"), Err(\"failed\"));",
+            )]
+            pub fn ok_or_else<E>(self, err: impl FnOnce() -> E) -> ::core::result::Result<(), E> { if self.0 != 0 { Ok(()) } else { Err(err()) } }
+
+            #[doc = concat!("Wrap a raw `", stringify!($int), "` as-is, without normalizing - e.g. when taking ownership of a value written by C.")]
+            /// Usable in `const` context, e.g. for lookup tables.  Note `from_raw(2)` is truthy-equal to `TRUE`, but not bit-equal (see [into_raw](Self::into_raw)).
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const TABLE : [", stringify!($name), "; 3] = [", stringify!($name), "::from_raw(0), ", stringify!($name), "::from_raw(1), ", stringify!($name), "::from_raw(2)];\n",
+                "assert_eq!(TABLE, [false, true, true]);\n",
+                "assert_eq!(TABLE[2], ", stringify!($name), "::TRUE);\n",
+                "assert_eq!(TABLE[2].into_raw(), 2);",
+            )]
+            pub const fn from_raw(raw: $int) -> Self { Self(raw) }
+
+            #[doc = concat!("Wrap a raw `", stringify!($int), "` only if it's exactly `0` or `1` - e.g. to catch corrupt FFI data that [From] would quietly accept as truthy.")]
+            ///
+            #[doc = concat!("(A `TryFrom<", stringify!($int), ">` impl isn't possible, as it would conflict with the blanket impl backed by `From<", stringify!($int), ">`.)")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::try_from_raw(1), Ok(", stringify!($name), "::TRUE));\n",
+                "assert_eq!(", stringify!($name), "::try_from_raw(2), Err(NonCanonicalError(2)));",
+            )]
+            pub const fn try_from_raw(raw: $int) -> ::core::result::Result<Self, $crate::NonCanonicalError<$int>> { match raw { 0 | 1 => Ok(Self(raw)), _ => Err($crate::NonCanonicalError(raw)) } }
+
+            #[doc = concat!("Unwrap to the raw `", stringify!($int), "` as-is, without normalizing - e.g. when moving the value into an FFI out-param buffer.")]
+            /// Also the way to check what was actually stored, since comparisons are otherwise truthiness based - see the [bool8] and [bool32] examples.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).into_raw(), 2);\n",
+                "assert_eq!(", stringify!($name), "::from(true).into_raw(), 1);",
+            )]
+            pub const fn into_raw(self) -> $int { self.0 }
+
+            #[doc = concat!("Pointer to the underlying `", stringify!($int), "` - e.g. for an FFI in-param.")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let flag = ", stringify!($name), "::from_raw(2);\n",
+                "assert_eq!(unsafe { *flag.as_ptr() }, 2);",
+            )]
+            pub fn as_ptr(&self) -> *const $int { &self.0 }
+
+            #[doc = concat!("Mutable pointer to the underlying `", stringify!($int), "` - e.g. for an FFI out-param.  Writing any nonzero value through it is truthy.")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut flag = ", stringify!($name), "::FALSE;\n",
+                "unsafe { *flag.as_mut_ptr() = 2 }; // e.g. written by an FFI call\n",
+                "assert_eq!(flag, true);",
+            )]
+            pub fn as_mut_ptr(&mut self) -> *mut $int { &mut self.0 }
+
+            /// Canonicalize the result of `f` - reads well for complex conditions: `from_predicate(|| len > 0 && enabled)`
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let (len, enabled) = (3, true);\n",
+                "assert_eq!(", stringify!($name), "::from_predicate(|| len > 0 && enabled).into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::from_predicate(|| len > 3).into_raw(), 0);",
+            )]
+            pub fn from_predicate(f: impl FnOnce() -> bool) -> Self { Self::new(f()) }
+
+            /// Canonical truthiness of a C `memcmp`/`strcmp`-style result: `0` is false, anything else (including negatives) is true.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_sign(-1).into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::from_sign(0).into_raw(), 0);",
+            )]
+            pub fn from_sign(v: i32) -> Self { Self::new(v != 0) }
+
+            /// Canonical truthiness of a count: `0` is false, anything else is true - e.g. `from_count(errors.iter().filter(|e| e.fatal).count())`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const ANY : ", stringify!($name), " = ", stringify!($name), "::from_count(3);\n",
+                "assert_eq!(ANY.into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::from_count(0).into_raw(), 0);",
+            )]
+            pub const fn from_count(n: usize) -> Self { Self::new(n != 0) }
+
+            /// Canonical truthiness of bit `index` of a C flag `word`.  Out of range bits (`index >= 32`) are `FALSE`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const CAPS : u32 = 0b101;\n",
+                "const _ : () = assert!(", stringify!($name), "::bit(CAPS, 2).is_true() && ", stringify!($name), "::bit(CAPS, 40).is_false());\n",
+                "assert_eq!([", stringify!($name), "::bit(CAPS, 0), ", stringify!($name), "::bit(CAPS, 1)], [true, false]);",
+            )]
+            pub const fn bit(word: u32, index: u32) -> Self { if index < 32 { Self(((word >> index) & 1) as $int) } else { Self::FALSE } }
+
+            #[doc = concat!("Lazily convert a stream of raw `", stringify!($int), "`s into [`", stringify!($name), "`](Self)s, normalizing truthy values to `1`.")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut flags = ", stringify!($name), "::normalize_iter([0, 2].iter().copied());\n",
+                "assert_eq!(flags.next().map(", stringify!($name), "::into_raw), Some(0));\n",
+                "assert_eq!(flags.next().map(", stringify!($name), "::into_raw), Some(1));\n",
+                "assert_eq!(flags.next(), None);",
+            )]
+            pub fn normalize_iter(iter: impl Iterator<Item = $int>) -> impl Iterator<Item = Self> { iter.map(|value| Self::new(value != 0)) }
+
+            /// `N` canonical copies of `value`, for initializing wide FFI arrays.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let flags = ", stringify!($name), "::splat::<3>(bool32::from_raw(-1));\n",
+                "assert_eq!(flags.map(", stringify!($name), "::into_raw), [1, 1, 1]);",
+            )]
+            pub fn splat<const N: usize>(value: impl ::core::convert::Into<bool>) -> [Self; N] { [Self::new(value.into()); N] }
+
+            #[doc = concat!("Like [core::array::from_fn], but canonicalizing each [bool] into a [`", stringify!($name), "`](Self).")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let even : [", stringify!($name), "; 4] = ", stringify!($name), "::from_fn(|i| i % 2 == 0);\n",
+                "assert_eq!(even, [true, false, true, false]);",
+            )]
+            pub fn from_fn<const N: usize>(mut f: impl FnMut(usize) -> bool) -> [Self; N] { ::core::array::from_fn(|i| Self::new(f(i))) }
+
+            /// Canonical logical AND of every flag (`TRUE` if `flags` is empty.)
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::all_of(&[", stringify!($name), "::TRUE, ", stringify!($name), "::from_raw(2)]).into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::all_of(&[", stringify!($name), "::TRUE, ", stringify!($name), "::FALSE]), false);\n",
+                "assert_eq!(", stringify!($name), "::all_of(&[]), true);",
+            )]
+            pub fn all_of(flags: &[Self]) -> Self { Self::new(flags.iter().all(|f| f.is_true())) }
+
+            /// Canonical logical OR of every flag (`FALSE` if `flags` is empty.)
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::any_of(&[", stringify!($name), "::FALSE, ", stringify!($name), "::from_raw(2)]).into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::any_of(&[", stringify!($name), "::FALSE]), false);\n",
+                "assert_eq!(", stringify!($name), "::any_of(&[]), false);",
+            )]
+            pub fn any_of(flags: &[Self]) -> Self { Self::new(flags.iter().any(|f| f.is_true())) }
+
+            /// Write `values` as a CSV row of canonical integers (e.g. `1,0,1`.)  Writes nothing for an empty slice.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "# extern crate alloc;\n",
+                "let mut csv = alloc::string::String::new();\n",
+                stringify!($name), "::write_csv_flags(&[", stringify!($name), "::TRUE, ", stringify!($name), "::FALSE, ", stringify!($name), "::from_raw(2)], &mut csv).unwrap();\n",
+                "assert_eq!(csv, \"1,0,1\");",
+            )]
+            pub fn write_csv_flags(values: &[Self], out: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 { out.write_char(',')?; }
+                    out.write_char(if value.is_true() { '1' } else { '0' })?;
+                }
+                Ok(())
+            }
+
+            /// Write a `name=true`/`name=false` line per flag, e.g. to dump a decoded C flag structure.
+            /// Returns [fmt::Error](core::fmt::Error) without writing anything if `names` and `values` differ in length.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "# extern crate alloc;\n",
+                "let flags = [", stringify!($name), "::TRUE, ", stringify!($name), "::FALSE, ", stringify!($name), "::from_raw(2)];\n",
+                "let mut dump = alloc::string::String::new();\n",
+                stringify!($name), "::write_named_flags(&[\"a\", \"b\", \"c\"], &flags, &mut dump).unwrap();\n",
+                "assert_eq!(dump, \"a=true\\nb=false\\nc=true\\n\");\n",
+                "assert!(", stringify!($name), "::write_named_flags(&[\"a\"], &flags, &mut dump).is_err());",
+            )]
+            pub fn write_named_flags(names: &[&str], values: &[Self], out: &mut impl ::core::fmt::Write) -> ::core::fmt::Result {
+                if names.len() != values.len() { return Err(::core::fmt::Error); }
+                for (name, value) in names.iter().zip(values.iter()) {
+                    writeln!(out, "{}={}", name, value)?;
+                }
+                Ok(())
+            }
+
+            /// Map every element to canonical `0` or `1` - usable at compile time to guarantee a `const` table has no other truthy values.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const TABLE : [", stringify!($name), "; 3] = ", stringify!($name), "::canonicalize_array([", stringify!($name), "::from_raw(0), ", stringify!($name), "::from_raw(2), ", stringify!($name), "::from_raw(0x40)]);\n",
+                "assert_eq!(TABLE.map(", stringify!($name), "::into_raw), [0, 1, 1]);",
+            )]
+            pub const fn canonicalize_array<const N: usize>(mut array: [Self; N]) -> [Self; N] {
+                let mut i = 0;
+                while i < N {
+                    array[i] = array[i].normalized();
+                    i += 1;
+                }
+                array
+            }
+
+            /// Returns `(truthiness, is_canonical)`, where canonical means the underlying value is exactly `0` or `1`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).to_bool_checked(), (true, false));\n",
+                "assert_eq!(", stringify!($name), "::TRUE.to_bool_checked(), (true, true));\n",
+                "assert_eq!(", stringify!($name), "::FALSE.to_bool_checked(), (false, true));",
+            )]
+            pub fn to_bool_checked(self) -> (bool, bool) { (self.0 != 0, self.0 == 0 || self.0 == 1) }
+
+            /// Truthiness if the raw value is canonical (`0` or `1`), like [Option::expect].
+            ///
+            /// # Panics
+            /// If the raw value is non-canonical, with a message including `msg` and the raw value.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::TRUE.expect_canonical(\"caps.enabled\"), true);\n",
+                "assert!(std::panic::catch_unwind(|| ", stringify!($name), "::from_raw(2).expect_canonical(\"caps.enabled\")).is_err());",
+            )]
+            #[track_caller] pub fn expect_canonical(self, msg: &str) -> bool { match self.0 { 0 => false, 1 => true, raw => panic!("{}: non-canonical raw value {:#0w$x}", msg, raw, w = 2 + 2 * ::core::mem::size_of::<$int>()) } }
+
+            /// Compare the underlying bit patterns instead of truthiness - e.g. to deterministically sort or dedup captured FFI values.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut captured = [", stringify!($name), "::from_raw(2), ", stringify!($name), "::TRUE, ", stringify!($name), "::FALSE];\n",
+                "captured.sort_by(|a, b| a.cmp_raw(*b));\n",
+                "assert_eq!(captured.map(", stringify!($name), "::into_raw), [0, 1, 2]);",
+            )]
+            pub fn cmp_raw(self, other: Self) -> ::core::cmp::Ordering { ::core::cmp::Ord::cmp(&self.0, &other.0) }
+
+            /// `"true"` or `"false"` by truthiness - a deterministic, allocation-free key for e.g. cache file names.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).stable_key(), \"true\");\n",
+                "assert_eq!(", stringify!($name), "::FALSE.stable_key(), \"false\");",
+            )]
+            pub fn stable_key(self) -> &'static str { if self.0 != 0 { "true" } else { "false" } }
+
+            /// `0` if falsey, `1` if truthy - for `sort_by_key`, grouping by truthiness regardless of raw bits.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut flags = [", stringify!($name), "::from_raw(2), ", stringify!($name), "::FALSE, ", stringify!($name), "::TRUE];\n",
+                "flags.sort_by_key(|f| f.sort_key());\n",
+                "assert_eq!(flags, [false, true, true]);\n",
+                "assert_eq!(flags[1].into_raw(), 2); // stable: raw values within a group keep their order",
+            )]
+            pub fn sort_key(self) -> u8 { (self.0 != 0) as u8 }
+
+            /// [Display](core::fmt::Display) using custom `labels` (e.g. `"Yes"`/`"No"`) instead of `"true"`/`"false"`.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "# extern crate alloc;\n",
+                "struct YesNo;\n",
+                "impl BoolLabels for YesNo { fn true_label(&self) -> &str { \"Yes\" } fn false_label(&self) -> &str { \"No\" } }\n",
+                "assert_eq!(alloc::format!(\"[{:>4}]\", ", stringify!($name), "::from_raw(2).display_with(&YesNo)), \"[ Yes]\");\n",
+                "assert_eq!(alloc::format!(\"{}\", ", stringify!($name), "::FALSE.display_with(&YesNo)), \"No\");",
+            )]
+            pub fn display_with<'a>(self, labels: &'a impl $crate::BoolLabels) -> impl ::core::fmt::Display + 'a { $crate::__private::DisplayWith(self.0 != 0, labels) }
+
+            /// `[self]`, for generic APIs that want fixed-size arrays.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_array(), [", stringify!($name), "::TRUE]);",
+            )]
+            pub fn as_array(self) -> [Self; 1] { [self] }
+
+            /// Canonical `bool8` of the same truthiness.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_bool8().into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.as_bool8(), bool8::FALSE);",
+            )]
+            pub fn as_bool8(self) -> $crate::bool8 { $crate::bool8::new(self.0 != 0) }
+
+            /// Canonical `bool16` of the same truthiness.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_bool16().into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.as_bool16(), bool16::FALSE);",
+            )]
+            pub fn as_bool16(self) -> $crate::bool16 { $crate::bool16::new(self.0 != 0) }
+
+            /// Canonical `bool32` of the same truthiness.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_bool32().into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.as_bool32(), bool32::FALSE);",
+            )]
+            pub fn as_bool32(self) -> $crate::bool32 { $crate::bool32::new(self.0 != 0) }
+
+            /// Canonical `bool64` of the same truthiness.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_bool64().into_raw(), 1);\n",
+                "assert_eq!(", stringify!($name), "::FALSE.as_bool64(), bool64::FALSE);",
+            )]
+            pub fn as_bool64(self) -> $crate::bool64 { $crate::bool64::new(self.0 != 0) }
+
+            /// `true` if `self` and `other` have the same truthiness - usable in `const` contexts, unlike [PartialEq].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::from_raw(2).eq_bool8(bool8::from_raw(0xff)));\n",
+                "assert!(!", stringify!($name), "::FALSE.eq_bool8(bool8::TRUE));",
+            )]
+            pub const fn eq_bool8(self, other: $crate::bool8) -> bool { (self.0 != 0) == other.is_true() }
+
+            /// `true` if `self` and `other` have the same truthiness - usable in `const` contexts, unlike [PartialEq].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::from_raw(2).eq_bool16(bool16::from_raw(0x8000)));\n",
+                "assert!(!", stringify!($name), "::FALSE.eq_bool16(bool16::TRUE));",
+            )]
+            pub const fn eq_bool16(self, other: $crate::bool16) -> bool { (self.0 != 0) == other.is_true() }
+
+            /// `true` if `self` and `other` have the same truthiness - usable in `const` contexts, unlike [PartialEq].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::from_raw(2).eq_bool32(bool32::from_raw(-1)));\n",
+                "assert!(!", stringify!($name), "::FALSE.eq_bool32(bool32::TRUE));",
+            )]
+            pub const fn eq_bool32(self, other: $crate::bool32) -> bool { (self.0 != 0) == other.is_true() }
+
+            /// `true` if `self` and `other` have the same truthiness - usable in `const` contexts, unlike [PartialEq].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "const _ : () = assert!(", stringify!($name), "::from_raw(2).eq_bool64(bool64::from_raw(u64::MAX)));\n",
+                "assert!(!", stringify!($name), "::FALSE.eq_bool64(bool64::TRUE));",
+            )]
+            pub const fn eq_bool64(self, other: $crate::bool64) -> bool { (self.0 != 0) == other.is_true() }
+
+            #[doc = concat!("The raw bytes of the underlying `", stringify!($int), "` (native endian), for generic APIs that want fixed-size byte arrays.")]
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::FALSE.as_byte_array(), [0; core::mem::size_of::<", stringify!($name), ">()]);\n",
+                "assert_eq!(", stringify!($name), "::from_raw(2).as_byte_array(), ", stringify!($name), "::from_raw(2).into_raw().to_ne_bytes());",
+            )]
+            pub fn as_byte_array(self) -> [u8; ::core::mem::size_of::<$int>()] { self.0.to_ne_bytes() }
+
+            /// Volatile read of a memory-mapped or shared-memory flag.  Never elided or reordered relative to other volatile operations.
+            ///
+            /// # Safety
+            /// `ptr` must be [valid](core::ptr#safety) for reads and properly aligned, as per [core::ptr::read_volatile].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let register = 2;\n",
+                "let flag = unsafe { ", stringify!($name), "::read_volatile(&register) };\n",
+                "assert_eq!(flag.into_raw(), 2);",
+            )]
+            pub unsafe fn read_volatile(ptr: *const $int) -> Self { Self(::core::ptr::read_volatile(ptr)) }
+
+            /// Volatile write of a memory-mapped or shared-memory flag.  Writes the canonical `0` or `1`, never other truthy values.
+            ///
+            /// # Safety
+            /// `ptr` must be [valid](core::ptr#safety) for writes and properly aligned, as per [core::ptr::write_volatile].
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "let mut register = 0;\n",
+                "unsafe { ", stringify!($name), "::write_volatile(&mut register, ", stringify!($name), "::from_raw(0x40)) };\n",
+                "assert_eq!(register, 1); // writes are canonical",
+            )]
+            pub unsafe fn write_volatile(ptr: *mut $int, value: Self) { ::core::ptr::write_volatile(ptr, value.normalized().0) }
+
+            #[doc = concat!("Fill a `[", stringify!($name), "; N]` from exactly `N` [bool]s, or return `Err(count)` if the iterator yielded fewer.")]
+            ///
+            /// Returns `Err(N + 1)` as soon as the iterator yields more than `N` - without draining the rest, so unbounded iterators are fine.
+            ///
+            #[doc = $crate::__abibool_example!($name,
+                "assert_eq!(", stringify!($name), "::collect_array::<2>([true, false]), Ok([", stringify!($name), "::TRUE, ", stringify!($name), "::FALSE]));\n",
+                "assert_eq!(", stringify!($name), "::collect_array::<2>([true]), Err(1));\n",
+                "assert_eq!(", stringify!($name), "::collect_array::<2>(core::iter::repeat(true)), Err(3)); // more than 2",
+            )]
+            pub fn collect_array<const N: usize>(iter: impl IntoIterator<Item = bool>) -> ::core::result::Result<[Self; N], usize> {
+                let mut iter = iter.into_iter();
+                let mut array = [Self::FALSE; N];
+                for (i, slot) in array.iter_mut().enumerate() {
+                    *slot = Self::new(iter.next().ok_or(i)?);
+                }
+                match iter.next() {
+                    None    => Ok(array),
+                    Some(_) => Err(N + 1),
+                }
+            }
+        }
+
+        impl ::core::convert::AsRef<bool>  for $name { fn as_ref(&self) -> &bool { if self.0 != 0 { &true } else { &false } } }
+        impl ::core::borrow::Borrow<bool>  for $name { fn borrow(&self) -> &bool { if self.0 != 0 { &true } else { &false } } }
+        impl ::core::default::Default      for $name { fn default() -> Self { Self::FALSE } }
+        impl ::core::fmt::Debug            for $name { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { ::core::fmt::Debug  ::fmt(&(self.0 != 0), f) } }
+        impl ::core::fmt::Display          for $name { fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result { ::core::fmt::Display::fmt(&(self.0 != 0), f) } }
+        impl ::core::str::FromStr          for $name { type Err = $crate::ParseBoolError; fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> { $crate::__private::parse_bool(s).map(Self::new) } }
+
+        // Raw integer conversions don't normalize.
+        impl ::core::convert::From<bool  > for $name  { fn from(value: bool  ) -> Self { Self(value as $int) } }
+        impl ::core::convert::From<$int  > for $name  { fn from(value: $int  ) -> Self { Self(value) } }
+        impl ::core::convert::From<$name > for bool   { fn from(value: $name ) -> Self { value.0 != 0 } }
+        impl ::core::convert::From<$name > for $int   { fn from(value: $name ) -> Self { value.0 } }
+        impl<'a> ::core::convert::From<&'a $int > for &'a $name { fn from(value: &'a $int ) -> Self { unsafe { &*(value as *const $int as *const $name) } } } // #[repr(transparent)]
+        impl<'a> ::core::convert::From<&'a $name> for &'a $int  { fn from(value: &'a $name) -> Self { &value.0 } }
+
+        // XXX: Consider `type Output = Self` in the next breaking revision of abibool, so `x = !x` works.  Until then, see `toggled` / `toggle`.
+        impl ::core::ops::Not for $name { type Output = bool; fn not(self) -> Self::Output { self.0 == 0 } }
+
+        // Bitwise operators act on truthiness too, always yielding canonical results: `bool8(0x80) & bool8(0x40)` is `TRUE`, not `bool8(0x00)`.
+        impl ::core::ops::BitAnd<$name> for $name { type Output = $name; fn bitand(self, rhs: $name) -> Self::Output { Self::new((self.0 != 0) & (rhs.0 != 0)) } }
+        impl ::core::ops::BitAnd<bool > for $name { type Output = $name; fn bitand(self, rhs: bool ) -> Self::Output { Self::new((self.0 != 0) & rhs) } }
+        impl ::core::ops::BitOr <$name> for $name { type Output = $name; fn bitor (self, rhs: $name) -> Self::Output { Self::new((self.0 != 0) | (rhs.0 != 0)) } }
+        impl ::core::ops::BitOr <bool > for $name { type Output = $name; fn bitor (self, rhs: bool ) -> Self::Output { Self::new((self.0 != 0) | rhs) } }
+        impl ::core::ops::BitXor<$name> for $name { type Output = $name; fn bitxor(self, rhs: $name) -> Self::Output { Self::new((self.0 != 0) ^ (rhs.0 != 0)) } } // bool8(0x03) ^ bool8(0x01) is FALSE, not bool8(0x02)
+        impl ::core::ops::BitXor<bool > for $name { type Output = $name; fn bitxor(self, rhs: bool ) -> Self::Output { Self::new((self.0 != 0) ^ rhs) } }
+
+        // Assignment (`flags.any_error |= failed;`) writes a canonical value - `bool8(0x40) | true` is `bool8(1)`, not `bool8(0x41)`.
+        impl ::core::ops::BitAndAssign<$name> for $name { fn bitand_assign(&mut self, rhs: $name) { *self = *self & rhs } }
+        impl ::core::ops::BitAndAssign<bool > for $name { fn bitand_assign(&mut self, rhs: bool ) { *self = *self & rhs } }
+        impl ::core::ops::BitOrAssign <$name> for $name { fn bitor_assign (&mut self, rhs: $name) { *self = *self | rhs } }
+        impl ::core::ops::BitOrAssign <bool > for $name { fn bitor_assign (&mut self, rhs: bool ) { *self = *self | rhs } }
+        impl ::core::ops::BitXorAssign<$name> for $name { fn bitxor_assign(&mut self, rhs: $name) { *self = *self ^ rhs } }
+        impl ::core::ops::BitXorAssign<bool > for $name { fn bitxor_assign(&mut self, rhs: bool ) { *self = *self ^ rhs } }
+
+        // All comparisons, hashes, etc. are based on truthiness, not the underlying bit patterns!
+        impl ::core::cmp::Eq                for $name {}
+        impl ::core::cmp::PartialEq <$name> for $name { fn eq(&self, other: &$name) -> bool { (self.0 != 0) == (other.0 != 0) } }
+        impl ::core::cmp::PartialEq <bool > for $name { fn eq(&self, other: &bool ) -> bool { (self.0 != 0) == *other } }
+        impl ::core::cmp::PartialEq <$name> for bool  { fn eq(&self, other: &$name) -> bool { (other.0 != 0) == *self } }
+        impl ::core::cmp::PartialOrd<$name> for $name { fn partial_cmp(&self, other: &$name) -> Option<::core::cmp::Ordering> { Some(::core::cmp::Ord::cmp(self, other)) } }
+        impl ::core::cmp::PartialOrd<bool > for $name { fn partial_cmp(&self, other: &bool ) -> Option<::core::cmp::Ordering> { ::core::cmp::PartialOrd::partial_cmp(&(self.0 != 0), other) } }
+        impl ::core::cmp::PartialOrd<$name> for bool  { fn partial_cmp(&self, other: &$name) -> Option<::core::cmp::Ordering> { ::core::cmp::PartialOrd::partial_cmp(self, &(other.0 != 0)) } }
+        impl ::core::cmp::Ord               for $name { fn cmp(&self, other: &$name) -> ::core::cmp::Ordering { ::core::cmp::Ord::cmp(&(self.0 != 0), &(other.0 != 0)) } }
+        impl ::core::hash::Hash             for $name { fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) { ::core::hash::Hash::hash(&(self.0 != 0), state) } }
+
+        impl ::core::ops::AddAssign<$name> for $crate::TrueCount { fn add_assign(&mut self, flag: $name) { self.0 += (flag.0 != 0) as usize } }
+        impl $crate::AbiBool for $name { fn is_true(&self) -> bool { self.0 != 0 } }
+        impl $crate::IntoAbiBool for $name { fn into_abi_u8(self) -> u8 { (self.0 != 0) as u8 } fn into_abi_i32(self) -> i32 { (self.0 != 0) as i32 } }
+    };
+}
+
+define_abibool!(@impl bool8(u8));
+define_abibool!(@impl bool16(u16));
+define_abibool!(@impl bool32(i32));
+define_abibool!(@impl bool64(u64));
+
+
+/// An atomic [bool8] - ABI-compatible with [bool8], e.g. for a flag in memory shared with a driver or another process.
+///
+/// Reads are based on truthiness (any nonzero value is `true`), writes are always canonical (`0` or `1`.)
+///
+/// ```
+/// # use abibool::*;
+/// use core::sync::atomic::Ordering::SeqCst;
+/// let flag = AtomicBool8::new(false);
+/// assert_eq!(flag.swap(true, SeqCst), false);
+/// assert_eq!(flag.compare_exchange(true, false, SeqCst, SeqCst), Ok(true));
+/// assert_eq!(flag.compare_exchange(true, false, SeqCst, SeqCst), Err(false));
+/// flag.store(true, SeqCst);
+/// assert!(flag.load(SeqCst));
+/// assert_eq!(flag.into_inner().into_raw(), 1);
+/// ```
+#[cfg(target_has_atomic = "8")]
+#[repr(transparent)] pub struct AtomicBool8(AtomicU8);
+
+#[cfg(target_has_atomic = "8")]
+impl AtomicBool8 {
+    /// A new atomic holding canonical `value`.
+    pub const fn new(value: bool) -> Self { Self(AtomicU8::new(value as u8)) }
+
+    /// Truthiness of the current value.
+    pub fn load(&self, order: atomic::Ordering) -> bool { self.0.load(order) != 0 }
+
+    /// Store canonical `value`.
+    pub fn store(&self, value: bool, order: atomic::Ordering) { self.0.store(value as u8, order) }
+
+    /// Store canonical `value`, returning the previous truthiness - e.g. for one-shot latches.
+    pub fn swap(&self, value: bool, order: atomic::Ordering) -> bool { self.0.swap(value as u8, order) != 0 }
+
+    /// Store canonical `new` if the current value's truthiness is `current`, like [AtomicBool::compare_exchange](atomic::AtomicBool::compare_exchange).
+    ///
+    /// Any truthy value matches `current == true` - not just `1`.
+    /// Returns the previous truthiness: `Ok` if it was `current` (and `new` was stored), `Err` otherwise.
+    pub fn compare_exchange(&self, current: bool, new: bool, success: atomic::Ordering, failure: atomic::Ordering) -> Result<bool, bool> {
+        let mut raw = self.0.load(failure);
+        loop {
+            if (raw != 0) != current { return Err(raw != 0) }
+            match self.0.compare_exchange(raw, new as u8, success, failure) {
+                Ok(_)       => return Ok(current),
+                Err(actual) => raw = actual, // e.g. another truthy value was written - retry if still `current`
+            }
+        }
+    }
+
+    /// Consume the atomic, returning the contained value as-is.
+    pub fn into_inner(self) -> bool8 { bool8(self.0.into_inner()) }
+}
+
+/// An atomic [bool32] - ABI-compatible with [bool32], e.g. for a flag in memory shared with a driver or another process.
+///
+/// Reads are based on truthiness (any nonzero value is `true`), writes are always canonical (`0` or `1`.)
+///
+/// ```
+/// # use abibool::*;
+/// use core::sync::atomic::Ordering::SeqCst;
+/// let mut shared = -1i32; // e.g. a `BOOL` written by another process
+/// let flag = unsafe { &*(&mut shared as *mut i32).cast::<AtomicBool32>() };
+/// assert_eq!(flag.compare_exchange(true, false, SeqCst, SeqCst), Ok(true)); // any truthy value matches `true`
+/// assert_eq!(flag.swap(true, SeqCst), false);
+/// assert!(flag.load(SeqCst));
+/// assert_eq!(shared, 1);
+/// ```
+#[cfg(target_has_atomic = "32")]
+#[repr(transparent)] pub struct AtomicBool32(AtomicI32);
+
+#[cfg(target_has_atomic = "32")]
+impl AtomicBool32 {
+    /// A new atomic holding canonical `value`.
+    pub const fn new(value: bool) -> Self { Self(AtomicI32::new(value as i32)) }
+
+    /// Truthiness of the current value.
+    pub fn load(&self, order: atomic::Ordering) -> bool { self.0.load(order) != 0 }
+
+    /// Store canonical `value`.
+    pub fn store(&self, value: bool, order: atomic::Ordering) { self.0.store(value as i32, order) }
+
+    /// Store canonical `value`, returning the previous truthiness - e.g. for one-shot latches.
+    pub fn swap(&self, value: bool, order: atomic::Ordering) -> bool { self.0.swap(value as i32, order) != 0 }
+
+    /// Store canonical `new` if the current value's truthiness is `current`, like [AtomicBool::compare_exchange](atomic::AtomicBool::compare_exchange).
+    ///
+    /// Any truthy value matches `current == true` - not just `1`.
+    /// Returns the previous truthiness: `Ok` if it was `current` (and `new` was stored), `Err` otherwise.
+    pub fn compare_exchange(&self, current: bool, new: bool, success: atomic::Ordering, failure: atomic::Ordering) -> Result<bool, bool> {
+        let mut raw = self.0.load(failure);
+        loop {
+            if (raw != 0) != current { return Err(raw != 0) }
+            match self.0.compare_exchange(raw, new as i32, success, failure) {
+                Ok(_)       => return Ok(current),
+                Err(actual) => raw = actual, // e.g. another truthy value was written - retry if still `current`
+            }
+        }
+    }
+
+    /// Consume the atomic, returning the contained value as-is.
+    pub fn into_inner(self) -> bool32 { bool32(self.0.into_inner()) }
+}
+
+/// Types that can be marshaled into canonical (`0` or `1`) ABI boolean integers.
+///
+/// FFI wrappers can accept `impl IntoAbiBool` to take [bool], [bool8], [bool16], [bool32], or [bool64] uniformly.
+///
+/// ```
+/// # use abibool::*;
+/// fn set_enabled(enabled: impl IntoAbiBool) -> i32 { enabled.into_abi_i32() } // e.g. forwarded to a C fn taking BOOL
+/// assert_eq!(set_enabled(true), 1);
+/// assert_eq!(set_enabled(bool8::from_raw(0x80)), 1);
+/// assert_eq!(set_enabled(bool64::FALSE), 0);
+/// ```
+pub trait IntoAbiBool {
+    /// `1` if truthy, `0` otherwise - suitable for a [BOOLEAN](bool8) parameter or field.
+    fn into_abi_u8(self) -> u8;
+
+    /// `1` if truthy, `0` otherwise - suitable for a [BOOL](bool32) parameter or field.
+    fn into_abi_i32(self) -> i32;
+}
+
+impl IntoAbiBool for bool   { fn into_abi_u8(self) -> u8 { self as _ } fn into_abi_i32(self) -> i32 { self as _ } }
+
+/// Width-agnostic truthiness of [bool], [bool8], [bool16], [bool32], or [bool64].
+///
+/// [Ord] can't span widths (it requires `Rhs = Self`), so sort mixed collections by key instead - e.g. `flags.sort_by_key(|f| f.is_true())` for a `Vec<&dyn AbiBool>`.
+///
+/// ```
+/// # use abibool::*;
+/// fn any_set(flags: &[&dyn AbiBool]) -> bool { flags.iter().any(|f| f.is_true()) }
+/// assert!( any_set(&[&bool8::FALSE, &bool32::from_raw(-1)]));
+/// assert!(!any_set(&[&false, &bool16::FALSE]));
+/// ```
+pub trait AbiBool {
+    /// `true` if nonzero.
+    fn is_true(&self) -> bool;
+}
+
+impl AbiBool for bool       { fn is_true(&self) -> bool { *self } }
 
-    /// bool32(`1`)
-    pub const TRUE  : bool32 = bool32(1);
+/// Custom labels for `display_with` - e.g. `"Yes"`/`"No"`, or localized strings.
+pub trait BoolLabels {
+    /// The label for truthy values - e.g. `"Yes"`.
+    fn true_label(&self) -> &str;
 
-    pub fn from(value: impl Into<Self>) -> Self { value.into() }
+    /// The label for falsey values - e.g. `"No"`.
+    fn false_label(&self) -> &str;
 }
 
 
+/// Wrap a [bool8], [bool32], etc. to [Debug] both truthiness and the raw value - e.g. `Bool8 { truthy: true, raw: 0xff }`.
+///
+/// Handy for temporarily wrapping suspicious fields of a large FFI struct, where a plain `true` would hide non-canonical values.
+///
+/// ```
+/// # extern crate alloc;
+/// # use abibool::*;
+/// assert_eq!(alloc::format!("{:?}", Diag(bool8::from_raw(0xff))), "Bool8 { truthy: true, raw: 0xff }");
+/// assert_eq!(alloc::format!("{:?}", bool8::from_raw(0xff)), "true");
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Diag<B>(pub B);
+
+impl Debug for Diag<bool8 > { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_struct("Bool8" ).field("truthy", &bool::from(self.0)).field("raw", &format_args!("{:#04x}",  self.0.0)).finish() } }
+impl Debug for Diag<bool16> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_struct("Bool16").field("truthy", &bool::from(self.0)).field("raw", &format_args!("{:#06x}",  self.0.0)).finish() } }
+impl Debug for Diag<bool32> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_struct("Bool32").field("truthy", &bool::from(self.0)).field("raw", &format_args!("{:#010x}", self.0.0)).finish() } }
+impl Debug for Diag<bool64> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_struct("Bool64").field("truthy", &bool::from(self.0)).field("raw", &format_args!("{:#018x}", self.0.0)).finish() } }
+
+/// Wrap a [bool8], [bool32], etc. to [Eq], [Ord], and [Hash] the raw bit pattern instead of truthiness - e.g. `ByRaw(bool8::from_raw(0xff)) != ByRaw(bool8::TRUE)`.
+///
+/// **Not** the default semantics of this crate: only reach for this when distinct truthy values must stay distinct, like deduplicating raw device images in a `HashSet`.
+///
+/// ```
+/// # use abibool::*;
+/// let (a, b) = (bool8::from_raw(0xff), bool8::from_raw(0x01));
+/// assert!(a == b);
+/// assert!(ByRaw(a) != ByRaw(b));
+/// assert!(ByRaw(b) < ByRaw(a));
+/// ```
+///
+/// [Debug] shows the raw value too, so e.g. a failing `assert_ne!` can tell them apart: `ByRaw(0xff)`.
+#[derive(Clone, Copy, Default)]
+pub struct ByRaw<B>(pub B);
+
+impl Debug for ByRaw<bool8 > { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_tuple("ByRaw").field(&format_args!("{:#04x}",  self.0.0)).finish() } }
+impl Debug for ByRaw<bool16> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_tuple("ByRaw").field(&format_args!("{:#06x}",  self.0.0)).finish() } }
+impl Debug for ByRaw<bool32> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_tuple("ByRaw").field(&format_args!("{:#010x}", self.0.0)).finish() } }
+impl Debug for ByRaw<bool64> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.debug_tuple("ByRaw").field(&format_args!("{:#018x}", self.0.0)).finish() } }
+
+impl PartialEq for ByRaw<bool8 > { fn eq(&self, other: &Self) -> bool { self.0.0 == other.0.0 } }
+impl PartialEq for ByRaw<bool16> { fn eq(&self, other: &Self) -> bool { self.0.0 == other.0.0 } }
+impl PartialEq for ByRaw<bool32> { fn eq(&self, other: &Self) -> bool { self.0.0 == other.0.0 } }
+impl PartialEq for ByRaw<bool64> { fn eq(&self, other: &Self) -> bool { self.0.0 == other.0.0 } }
+impl Eq for ByRaw<bool8 > {}
+impl Eq for ByRaw<bool16> {}
+impl Eq for ByRaw<bool32> {}
+impl Eq for ByRaw<bool64> {}
+impl PartialOrd for ByRaw<bool8 > { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl PartialOrd for ByRaw<bool16> { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl PartialOrd for ByRaw<bool32> { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl PartialOrd for ByRaw<bool64> { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) } }
+impl Ord for ByRaw<bool8 > { fn cmp(&self, other: &Self) -> Ordering { self.0.0.cmp(&other.0.0) } }
+impl Ord for ByRaw<bool16> { fn cmp(&self, other: &Self) -> Ordering { self.0.0.cmp(&other.0.0) } }
+impl Ord for ByRaw<bool32> { fn cmp(&self, other: &Self) -> Ordering { self.0.0.cmp(&other.0.0) } }
+impl Ord for ByRaw<bool64> { fn cmp(&self, other: &Self) -> Ordering { self.0.0.cmp(&other.0.0) } }
+impl Hash for ByRaw<bool8 > { fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.0.0.hash(state) } }
+impl Hash for ByRaw<bool16> { fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.0.0.hash(state) } }
+impl Hash for ByRaw<bool32> { fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.0.0.hash(state) } }
+impl Hash for ByRaw<bool64> { fn hash<H: core::hash::Hasher>(&self, state: &mut H) { self.0.0.hash(state) } }
+
+/// A `[bool8; N]` with bulk operations, layout compatible with a C `BOOLEAN flags[N]` array.
+///
+/// Indexing reads truthiness: `array[i]` is a [bool].  Use [set](Self::set) or the `.0` array to write.
+///
+/// ```
+/// # use abibool::*;
+/// let mut flags = BoolArray([bool8::from_raw(0xff), bool8::FALSE, bool8::TRUE]);
+/// assert!(flags.any() && !flags.all() && flags.count() == 2 && flags[0]);
+/// flags.normalize();
+/// assert!(bool8::raw_eq_bytes(&flags.0, &[1, 0, 1]));
+/// flags.toggle();
+/// assert!(bool8::raw_eq_bytes(&flags.0, &[0, 1, 0]));
+/// flags.set(0, true);
+/// assert_eq!(<[bool; 3]>::from(flags), [true, true, false]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)] pub struct BoolArray<const N: usize>(pub [bool8; N]);
+
+impl<const N: usize> BoolArray<N> {
+    /// All canonical `false`.
+    pub const FALSE : Self = BoolArray([bool8::FALSE; N]);
+
+    /// All canonical `true`.
+    pub const TRUE  : Self = BoolArray([bool8::TRUE; N]);
+
+    /// `true` if every element is truthy (or if `N == 0`).
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// assert!(BoolArray([bool8::TRUE, bool8::from_raw(0xff)]).all());
+    /// assert!(!BoolArray([bool8::TRUE, bool8::FALSE]).all());
+    /// assert!(BoolArray::<0>::FALSE.all());
+    /// ```
+    pub fn all(&self) -> bool { self.0.iter().all(|f| f.is_true()) }
 
-impl AsRef<bool>  for bool8  { fn as_ref(&self) -> &bool { if bool::from(*self) { &true } else { &false } } }
-impl AsRef<bool>  for bool32 { fn as_ref(&self) -> &bool { if bool::from(*self) { &true } else { &false } } }
+    /// `true` if any element is truthy.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// assert!(BoolArray([bool8::FALSE, bool8::from_raw(0xff)]).any());
+    /// assert!(!BoolArray::<2>::FALSE.any());
+    /// ```
+    pub fn any(&self) -> bool { self.0.iter().any(|f| f.is_true()) }
+
+    /// The number of truthy elements.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// assert_eq!(BoolArray([bool8::from_raw(0xff), bool8::FALSE, bool8::TRUE]).count(), 2);
+    /// ```
+    pub fn count(&self) -> usize { self.0.iter().filter(|f| f.is_true()).count() }
+
+    /// Rewrite every element to a canonical `0` or `1`, preserving truthiness.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// let mut flags = BoolArray([bool8::from_raw(0xff), bool8::FALSE]);
+    /// flags.normalize();
+    /// assert!(bool8::raw_eq_bytes(&flags.0, &[1, 0]));
+    /// ```
+    pub fn normalize(&mut self) { self.0.iter_mut().for_each(bool8::normalize) }
+
+    /// Flip the truthiness of every element, leaving canonical values.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// let mut flags = BoolArray([bool8::from_raw(0xff), bool8::FALSE]);
+    /// flags.toggle();
+    /// assert!(bool8::raw_eq_bytes(&flags.0, &[0, 1]));
+    /// ```
+    pub fn toggle(&mut self) { self.0.iter_mut().for_each(bool8::toggle) }
+
+    /// The truthiness of element `index`, or [None] if out of bounds.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// let flags = BoolArray([bool8::from_raw(0xff)]);
+    /// assert_eq!(flags.get(0), Some(true));
+    /// assert_eq!(flags.get(1), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<bool> { self.0.get(index).map(|f| f.is_true()) }
+
+    /// Set element `index` to a canonical `value`.  Panics if out of bounds, like indexing.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// let mut flags = BoolArray::<2>::FALSE;
+    /// flags.set(1, true);
+    /// assert!(bool8::raw_eq_bytes(&flags.0, &[0, 1]));
+    /// ```
+    #[track_caller] pub fn set(&mut self, index: usize, value: bool) { self.0[index] = bool8::new(value) }
+}
+
+impl<const N: usize> Default for BoolArray<N> { fn default() -> Self { Self::FALSE } }
+impl<const N: usize> From<[bool8; N]> for BoolArray<N> { fn from(array: [bool8; N]) -> Self { BoolArray(array) } }
+impl<const N: usize> From<BoolArray<N>> for [bool8; N] { fn from(array: BoolArray<N>) -> Self { array.0 } }
+impl<const N: usize> From<[bool; N]> for BoolArray<N> { fn from(array: [bool; N]) -> Self { BoolArray(array.map(bool8::new)) } }
+impl<const N: usize> From<BoolArray<N>> for [bool; N] { fn from(array: BoolArray<N>) -> Self { array.0.map(bool::from) } }
+impl<const N: usize> AsRef<[bool8]> for BoolArray<N> { fn as_ref(&self) -> &[bool8] { &self.0 } }
+impl<const N: usize> AsMut<[bool8]> for BoolArray<N> { fn as_mut(&mut self) -> &mut [bool8] { &mut self.0 } }
+impl<const N: usize> core::ops::Index<usize> for BoolArray<N> { type Output = bool; #[track_caller] fn index(&self, index: usize) -> &bool { self.0[index].as_ref() } }
+
+/// A count of truthy values, accumulated with `count += flag;`.
+///
+/// ```
+/// # use abibool::*;
+/// let mut count = TrueCount::default();
+/// for flag in [bool32::from_raw(-1), bool32::FALSE, bool32::TRUE] { count += flag; }
+/// count += true;
+/// assert_eq!(usize::from(count), 3);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TrueCount(pub usize);
 
-impl Borrow<bool> for bool8  { fn borrow(&self) -> &bool { if bool::from(*self) { &true } else { &false } } }
-impl Borrow<bool> for bool32 { fn borrow(&self) -> &bool { if bool::from(*self) { &true } else { &false } } }
+impl AddAssign<bool  > for TrueCount { fn add_assign(&mut self, flag: bool  ) { self.0 += flag as usize } }
+impl From<TrueCount> for usize { fn from(count: TrueCount) -> Self { count.0 } }
+
+/// A flag that's explicitly [True](TriBool::True) or [False](TriBool::False), or [Unset](TriBool::Unset) and inherited - e.g. for layered FFI config.
+///
+/// ```
+/// # use abibool::*;
+/// let (global, user) = (TriBool::True, TriBool::Unset);
+/// assert_eq!(user.resolve(global == TriBool::True), bool8::TRUE);
+/// assert_eq!(!TriBool::True, TriBool::False);
+/// assert_eq!(TriBool::from(Some(bool8::from_raw(2))), TriBool::True);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TriBool {
+    True,
+    False,
+    #[default] Unset,
+}
+
+impl TriBool {
+    /// Collapse to a canonical [bool8], using `default` if [Unset](TriBool::Unset).
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// let (global, user) = (TriBool::True, TriBool::Unset);
+    /// assert_eq!(user.resolve(global == TriBool::True), bool8::TRUE);
+    /// assert_eq!(TriBool::False.resolve(true), bool8::FALSE);
+    /// ```
+    pub fn resolve(self, default: bool) -> bool8 {
+        match self {
+            TriBool::True   => bool8::TRUE,
+            TriBool::False  => bool8::FALSE,
+            TriBool::Unset  => bool8::from(default),
+        }
+    }
+
+    /// [Kleene](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics) AND: [False](TriBool::False) if either is, else [Unset](TriBool::Unset) if either is.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// assert_eq!(TriBool::Unset.and(TriBool::False), TriBool::False);
+    /// assert_eq!(TriBool::Unset.and(TriBool::True), TriBool::Unset);
+    /// assert_eq!(TriBool::True.and(TriBool::True), TriBool::True);
+    /// ```
+    pub fn and(self, other: TriBool) -> TriBool {
+        match (self, other) {
+            (TriBool::False, _) | (_, TriBool::False)   => TriBool::False,
+            (TriBool::True, TriBool::True)              => TriBool::True,
+            _                                           => TriBool::Unset,
+        }
+    }
+
+    /// [Kleene](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics) OR: [True](TriBool::True) if either is, else [Unset](TriBool::Unset) if either is.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// assert_eq!(TriBool::Unset.or(TriBool::True), TriBool::True);
+    /// assert_eq!(TriBool::Unset.or(TriBool::False), TriBool::Unset);
+    /// assert_eq!(TriBool::False.or(TriBool::False), TriBool::False);
+    /// ```
+    pub fn or(self, other: TriBool) -> TriBool {
+        match (self, other) {
+            (TriBool::True, _) | (_, TriBool::True)     => TriBool::True,
+            (TriBool::False, TriBool::False)            => TriBool::False,
+            _                                           => TriBool::Unset,
+        }
+    }
+}
+
+impl From<Option<bool8>> for TriBool { fn from(value: Option<bool8>) -> Self { match value { Some(b) if bool::from(b) => TriBool::True, Some(_) => TriBool::False, None => TriBool::Unset } } }
+impl Not for TriBool { type Output = TriBool; fn not(self) -> Self::Output { match self { TriBool::True => TriBool::False, TriBool::False => TriBool::True, TriBool::Unset => TriBool::Unset } } }
+impl From<TriBool> for Option<bool8> { fn from(value: TriBool) -> Self { match value { TriBool::True => Some(bool8::TRUE), TriBool::False => Some(bool8::FALSE), TriBool::Unset => None } } }
+
+/// The error returned when [FromStr](core::str::FromStr)-parsing a [bool8], [bool32], etc. fails.
+///
+/// Like [bool]'s own [FromStr](core::str::FromStr), `"true"` and `"false"` are accepted.
+/// Since these are integer-backed ABI types, `"1"` and `"0"` are accepted as well.
+/// Anything else - including other integers, whitespace, or different casing - is rejected.
+///
+/// ```
+/// # use abibool::*;
+/// assert_eq!("1".parse::<bool8>(), Ok(bool8::TRUE));
+/// assert_eq!("false".parse::<bool32>(), Ok(bool32::FALSE));
+/// assert!("TRUE".parse::<bool8>().is_err()); // case sensitive, like bool
+/// assert!(" 1"  .parse::<bool8>().is_err()); // no whitespace trimming
+/// assert!("2"   .parse::<bool8>().is_err()); // no other integers
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseBoolError(());
+
+impl Display for ParseBoolError { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.write_str("provided string was not `true`, `false`, `1`, or `0`") } }
+
+/// The error returned by [bool8::try_from_raw] etc. for raw values other than `0` or `1`.  Holds the offending raw value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NonCanonicalError<T>(pub T);
+
+impl<T: fmt::LowerHex> Display for NonCanonicalError<T> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { write!(f, "non-canonical boolean value {:#x}", self.0) } }
+
+// Implementation details of `define_abibool!`, public only so the expanded code can reach them from other crates.
+#[doc(hidden)] pub mod __private {
+    use super::*;
+
+    pub struct DisplayWith<'a, L: BoolLabels>(pub bool, pub &'a L);
+    impl<L: BoolLabels> Display for DisplayWith<'_, L> { fn fmt(&self, f: &mut Formatter) -> fmt::Result { f.pad(if self.0 { self.1.true_label() } else { self.1.false_label() }) } }
+
+    pub fn parse_bool(s: &str) -> Result<bool, ParseBoolError> { match s { "true" | "1" => Ok(true), "false" | "0" => Ok(false), _ => Err(ParseBoolError(())) } }
+}
+
+/// Fails const evaluation if `STRUCT_SIZE != SUM_OF_FIELDS` - i.e. if a `#[repr(C)]` struct has unexpected padding.
+///
+/// ```
+/// # use abibool::*;
+/// # use core::mem::size_of;
+/// #[repr(C)] struct Caps { a: bool8, b: bool8, c: u16, d: bool32 }
+/// const _ : () = assert_no_padding::<{size_of::<Caps>()}, {2 * size_of::<bool8>() + size_of::<u16>() + size_of::<bool32>()}>();
+/// ```
+///
+/// A [bool8] followed by a [bool32] leaves 3 bytes of padding for alignment, failing the build:
+///
+/// ```compile_fail
+/// # use abibool::*;
+/// # use core::mem::size_of;
+/// #[repr(C)] struct Padded { a: bool8, b: bool32 }
+/// const _ : () = assert_no_padding::<{size_of::<Padded>()}, {size_of::<bool8>() + size_of::<bool32>()}>();
+/// ```
+pub const fn assert_no_padding<const STRUCT_SIZE: usize, const SUM_OF_FIELDS: usize>() {
+    assert!(STRUCT_SIZE == SUM_OF_FIELDS, "struct has padding: its size doesn't match the sum of its fields' sizes");
+}
+
+// FFI users share these across threads - fail the build if a future field accidentally removes the auto-trait impls.
+const _ : () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<bool8>();
+    assert_send_sync::<bool16>();
+    assert_send_sync::<bool32>();
+    assert_send_sync::<bool64>();
+    assert_send_sync::<BoolArray<1>>();
+    assert_send_sync::<TrueCount>();
+    assert_send_sync::<TriBool>();
+    #[cfg(target_has_atomic = "8" )] assert_send_sync::<AtomicBool8>();
+    #[cfg(target_has_atomic = "32")] assert_send_sync::<AtomicBool32>();
+};
+
+// The slice casts, transmutes, and FFI use of these types rely on them matching their underlying integers - fail the build if a `#[repr(transparent)]` is ever dropped.
+const _ : () = {
+    use core::mem::{align_of, size_of};
+    assert!(size_of::<bool8 >() == size_of::<u8 >() && align_of::<bool8 >() == align_of::<u8 >());
+    assert!(size_of::<bool16>() == size_of::<u16>() && align_of::<bool16>() == align_of::<u16>());
+    assert!(size_of::<bool32>() == size_of::<i32>() && align_of::<bool32>() == align_of::<i32>());
+    assert!(size_of::<bool64>() == size_of::<u64>() && align_of::<bool64>() == align_of::<u64>());
+    assert!(size_of::<BoolArray<0>>() == 0 && size_of::<BoolArray<3>>() == 3 && align_of::<BoolArray<3>>() == 1);
+    #[cfg(target_has_atomic = "8" )] assert!(size_of::<AtomicBool8 >() == size_of::<bool8 >() && align_of::<AtomicBool8 >() == align_of::<bool8 >());
+    #[cfg(target_has_atomic = "32")] assert!(size_of::<AtomicBool32>() == size_of::<bool32>() && align_of::<AtomicBool32>() == align_of::<bool32>());
+};
+
+
+// Impls between a type and itself or `bool` (Default, Debug, From<bool>, operators, Eq, Ord, Hash, etc.) come from define_abibool! above.
+// Impls between widths, and ones specific to a width, are below.
+
+/// Single-byte buffer view of the raw [BOOLEAN](bool8), for byte-oriented FFI.
+///
+/// ```
+/// # use abibool::bool8;
+/// assert_eq!(AsRef::<[u8]>::as_ref(&bool8::from_raw(0x80)), &[0x80]);
+/// ```
+impl AsRef<[u8]>  for bool8  { fn as_ref(&self) -> &[u8] { core::slice::from_ref(&self.0) } }
+
+// Hash, Eq, and Ord all match bool's, as Borrow requires - which guarantees `HashMap<bool8, V>::get(&true)` etc. find `bool8(0xFF)` keys.
+// The reverse (`HashMap<bool, V>::get(&bool8)`) isn't provided - query with `&bool::from(flag)` instead.
 
 // DON'T IMPLEMENT:
 //  impl Borrow<BOOLEAN> for bool8  { ... }
@@ -77,77 +1552,488 @@ impl Borrow<bool> for bool32 { fn borrow(&self) -> &bool { if bool::from(*self)
 // We've gone to pains to make bool32 behave very much like bool, with `true` acting like a single value, even when the internal BOOL might be another truthy value like `-1`.
 
 // XXX: REMOVEME:  Too winapi specific, prone to misuse.  Main intent here is FFI interop.
-// Replace with `as_[mut_]_ptr` type constrained to matching-size integer types?
+// Use `as_[mut_]ptr` instead.
 impl Deref for bool8  { type Target = BOOLEAN; fn deref(&self) -> &Self::Target { &self.0 } }
 impl Deref for bool32 { type Target = BOOL;    fn deref(&self) -> &Self::Target { &self.0 } }
 impl DerefMut for bool8  { fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 } }
 impl DerefMut for bool32 { fn deref_mut(&mut self) -> &mut Self::Target { &mut self.0 } }
 
-impl Default for bool8  { fn default() -> Self { Self::FALSE } }
-impl Default for bool32 { fn default() -> Self { Self::FALSE } }
-impl Debug   for bool8  { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Debug  ::fmt(&bool::from(*self), f) } }
-impl Debug   for bool32 { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Debug  ::fmt(&bool::from(*self), f) } }
-impl Display for bool8  { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Display::fmt(&bool::from(*self), f) } }
-impl Display for bool32 { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Display::fmt(&bool::from(*self), f) } }
-
-impl From<bool   > for bool8   { fn from(value: bool   ) -> Self { Self(value as _) } }
-impl From<bool   > for bool32  { fn from(value: bool   ) -> Self { Self(value as _) } }
-impl From<BOOLEAN> for bool8   { fn from(value: BOOLEAN) -> Self { Self(value) } } // XXX: REMOVEME: replace with `{u,i}8`?
-impl From<BOOL   > for bool32  { fn from(value: BOOL   ) -> Self { Self(value) } } // XXX: REMOVEME: replace with `{u,i}32`?
-impl From<bool8  > for BOOLEAN { fn from(value: bool8  ) -> Self { value.0 } } // XXX: REMOVEME: replace with `{u,i}8`?
-impl From<bool32 > for BOOL    { fn from(value: bool32 ) -> Self { value.0 } } // XXX: REMOVEME: replace with `{u,i}32`?
-impl From<bool8  > for bool    { fn from(value: bool8  ) -> Self { value.0 != 0 } }
-impl From<bool32 > for bool    { fn from(value: bool32 ) -> Self { value.0 != 0 } }
-
-impl From<&BOOLEAN> for &bool8   { fn from(value: &BOOLEAN) -> Self { unsafe { transmute(value) } } } // XXX: REMOVEME: replace with `{u,i}8`?
-impl From<&BOOL   > for &bool32  { fn from(value: &BOOL   ) -> Self { unsafe { transmute(value) } } } // XXX: REMOVEME: replace with `{u,i}32`?
-impl From<&bool8  > for &BOOLEAN { fn from(value: &bool8  ) -> Self { unsafe { transmute(value) } } } // XXX: REMOVEME: replace with `{u,i}8`?
-impl From<&bool32 > for &BOOL    { fn from(value: &bool32 ) -> Self { unsafe { transmute(value) } } } // XXX: REMOVEME: replace with `{u,i}32`?
-
-// slices are always foreign, so we can't implement these - transmute yourself I guess
+#[cfg(target_has_atomic = "8" )] impl Default for AtomicBool8  { fn default() -> Self { Self::new(false) } }
+#[cfg(target_has_atomic = "32")] impl Default for AtomicBool32 { fn default() -> Self { Self::new(false) } }
+#[cfg(target_has_atomic = "8" )] impl Debug for AtomicBool8  { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Debug::fmt(&self.load(atomic::Ordering::Relaxed), f) } }
+#[cfg(target_has_atomic = "32")] impl Debug for AtomicBool32 { fn fmt(&self, f: &mut Formatter) -> fmt::Result { Debug::fmt(&self.load(atomic::Ordering::Relaxed), f) } }
+
+// Raw conversions for the opposite signedness, without normalizing - `From<u8>`, `From<i32>`, etc. come from define_abibool!.
+impl From<u32    > for bool32  { fn from(value: u32    ) -> Self { Self(value as _) } }
+impl From<bool32 > for u32     { fn from(value: bool32 ) -> Self { value.0 as _ } }
+
+impl<'a> From<&'a u32    > for &'a bool32  { fn from(value: &'a u32    ) -> Self { unsafe { transmute(value) } } }
+impl<'a> From<&'a bool32 > for &'a u32     { fn from(value: &'a bool32 ) -> Self { unsafe { transmute(value) } } }
+impl<'a> From<&'a bool   > for &'a bool8   { fn from(value: &'a bool   ) -> Self { unsafe { transmute(value) } } } // bool is always 0 or 1, and bool8 has the same size/align
+
+// DON'T IMPLEMENT:
+//  impl From<i8> for bool8 { ... }
+//  impl From<bool8> for i8 { ... }
+// A second integer `From` would make `bool8::from(1)` and `1.into()` ambiguous (`{integer}` only falls back to `i32`), breaking existing code.
+// Use `bool8::from(value as u8)` instead.  bool32's `From<u32>` is fine, as `i32` remains the fallback.
+
+// DON'T IMPLEMENT:
+//  impl From<&mut bool> for &mut bool8 { ... }
+// DerefMut (or any future setter) would let safe code write e.g. `2` through the view, which is UB to later read as `bool`.
+
+// slices are always foreign, so we can't implement these - use bool8::from_u8_slice, bool32::as_i32_slice, etc. instead
 // impl From<&[BOOLEAN]> for &[bool8  ] { fn from(value: &[BOOLEAN]) -> Self { unsafe { transmute(value) } } }
 // impl From<&[BOOL   ]> for &[bool32 ] { fn from(value: &[BOOL   ]) -> Self { unsafe { transmute(value) } } }
 // impl From<&[bool8  ]> for &[BOOLEAN] { fn from(value: &[bool8  ]) -> Self { unsafe { transmute(value) } } }
 // impl From<&[bool32 ]> for &[BOOL   ] { fn from(value: &[bool32 ]) -> Self { unsafe { transmute(value) } } }
 
 // All comparisons, hashes, etc. are based on truthiness, not the underlying bit patterns!
+// This carries over to core's `impl PartialEq<[B]> for [A]`, so `[bool8(0xFF)] == [bool8(1)]` and `[bool8] == [bool32]` just work - no helper needed.
 
-impl Not               for bool8  { type Output = bool; fn not(self) -> Self::Output { self.0 == 0 } }
-impl Not               for bool32 { type Output = bool; fn not(self) -> Self::Output { self.0 == 0 } }
+// DON'T IMPLEMENT:
+//  impl PartialEq<u8 > for bool8  { ... }
+//  impl PartialEq<i32> for bool32 { ... }
+// Bit pattern equality would be at odds with every other comparison here.  Compare `flag.into_raw()` instead.
 
-impl Eq                for bool8  {}
-impl Eq                for bool32 {}
-impl PartialEq<bool8 > for bool8  { fn eq(&self, other: &bool8 ) -> bool { bool::from(*self) == bool::from(*other) } }
-impl PartialEq<bool32> for bool32 { fn eq(&self, other: &bool32) -> bool { bool::from(*self) == bool::from(*other) } }
-impl PartialEq<bool8 > for bool32 { fn eq(&self, other: &bool8 ) -> bool { bool::from(*self) == bool::from(*other) } }
-impl PartialEq<bool32> for bool8  { fn eq(&self, other: &bool32) -> bool { bool::from(*self) == bool::from(*other) } }
+// Mixed width bitwise operators act on truthiness too, yielding the wider type: `bool8(0x80) & bool32(0x40)` is `bool32::TRUE`.
+impl BitAnd<bool16> for bool8  { type Output = bool16; fn bitand(self, rhs: bool16) -> Self::Output { bool16::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool32> for bool8  { type Output = bool32; fn bitand(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool64> for bool8  { type Output = bool64; fn bitand(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool8 > for bool16 { type Output = bool16; fn bitand(self, rhs: bool8 ) -> Self::Output { bool16::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool32> for bool16 { type Output = bool32; fn bitand(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool64> for bool16 { type Output = bool64; fn bitand(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool8 > for bool32 { type Output = bool32; fn bitand(self, rhs: bool8 ) -> Self::Output { bool32::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool16> for bool32 { type Output = bool32; fn bitand(self, rhs: bool16) -> Self::Output { bool32::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool64> for bool32 { type Output = bool64; fn bitand(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool8 > for bool64 { type Output = bool64; fn bitand(self, rhs: bool8 ) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool16> for bool64 { type Output = bool64; fn bitand(self, rhs: bool16) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
+impl BitAnd<bool32> for bool64 { type Output = bool64; fn bitand(self, rhs: bool32) -> Self::Output { bool64::from(bool::from(self) & bool::from(rhs)) } }
 
-impl PartialEq<bool  > for bool8  { fn eq(&self, other: &bool  ) -> bool { bool::from(*self) == *other } }
-impl PartialEq<bool  > for bool32 { fn eq(&self, other: &bool  ) -> bool { bool::from(*self) == *other } }
-impl PartialEq<bool8 > for bool   { fn eq(&self, other: &bool8 ) -> bool { bool::from(*other) == *self } }
-impl PartialEq<bool32> for bool   { fn eq(&self, other: &bool32) -> bool { bool::from(*other) == *self } }
+impl BitOr<bool16>  for bool8  { type Output = bool16; fn bitor(self, rhs: bool16) -> Self::Output { bool16::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool32>  for bool8  { type Output = bool32; fn bitor(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool64>  for bool8  { type Output = bool64; fn bitor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool8 >  for bool16 { type Output = bool16; fn bitor(self, rhs: bool8 ) -> Self::Output { bool16::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool32>  for bool16 { type Output = bool32; fn bitor(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool64>  for bool16 { type Output = bool64; fn bitor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool8 >  for bool32 { type Output = bool32; fn bitor(self, rhs: bool8 ) -> Self::Output { bool32::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool16>  for bool32 { type Output = bool32; fn bitor(self, rhs: bool16) -> Self::Output { bool32::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool64>  for bool32 { type Output = bool64; fn bitor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool8 >  for bool64 { type Output = bool64; fn bitor(self, rhs: bool8 ) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool16>  for bool64 { type Output = bool64; fn bitor(self, rhs: bool16) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
+impl BitOr<bool32>  for bool64 { type Output = bool64; fn bitor(self, rhs: bool32) -> Self::Output { bool64::from(bool::from(self) | bool::from(rhs)) } }
 
-impl PartialOrd<bool8 > for bool8  { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
-impl PartialOrd<bool32> for bool32 { fn partial_cmp(&self, other: &bool32) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
-impl PartialOrd<bool8 > for bool32 { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl BitXor<bool16> for bool8  { type Output = bool16; fn bitxor(self, rhs: bool16) -> Self::Output { bool16::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool32> for bool8  { type Output = bool32; fn bitxor(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool64> for bool8  { type Output = bool64; fn bitxor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool8 > for bool16 { type Output = bool16; fn bitxor(self, rhs: bool8 ) -> Self::Output { bool16::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool32> for bool16 { type Output = bool32; fn bitxor(self, rhs: bool32) -> Self::Output { bool32::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool64> for bool16 { type Output = bool64; fn bitxor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool8 > for bool32 { type Output = bool32; fn bitxor(self, rhs: bool8 ) -> Self::Output { bool32::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool16> for bool32 { type Output = bool32; fn bitxor(self, rhs: bool16) -> Self::Output { bool32::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool64> for bool32 { type Output = bool64; fn bitxor(self, rhs: bool64) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool8 > for bool64 { type Output = bool64; fn bitxor(self, rhs: bool8 ) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool16> for bool64 { type Output = bool64; fn bitxor(self, rhs: bool16) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+impl BitXor<bool32> for bool64 { type Output = bool64; fn bitxor(self, rhs: bool32) -> Self::Output { bool64::from(bool::from(self) ^ bool::from(rhs)) } }
+
+impl PartialEq<bool16> for bool8  { fn eq(&self, other: &bool16) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool32> for bool8  { fn eq(&self, other: &bool32) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool64> for bool8  { fn eq(&self, other: &bool64) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool8 > for bool16 { fn eq(&self, other: &bool8 ) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool32> for bool16 { fn eq(&self, other: &bool32) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool64> for bool16 { fn eq(&self, other: &bool64) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool8 > for bool32 { fn eq(&self, other: &bool8 ) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool16> for bool32 { fn eq(&self, other: &bool16) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool64> for bool32 { fn eq(&self, other: &bool64) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool8 > for bool64 { fn eq(&self, other: &bool8 ) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool16> for bool64 { fn eq(&self, other: &bool16) -> bool { bool::from(*self) == bool::from(*other) } }
+impl PartialEq<bool32> for bool64 { fn eq(&self, other: &bool32) -> bool { bool::from(*self) == bool::from(*other) } }
+
+// No mixed width Ord - it requires `Rhs = Self`.  Use AbiBool::is_true as a sort key for heterogeneous collections.
+impl PartialOrd<bool16> for bool8  { fn partial_cmp(&self, other: &bool16) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
 impl PartialOrd<bool32> for bool8  { fn partial_cmp(&self, other: &bool32) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool64> for bool8  { fn partial_cmp(&self, other: &bool64) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool8 > for bool16 { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool32> for bool16 { fn partial_cmp(&self, other: &bool32) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool64> for bool16 { fn partial_cmp(&self, other: &bool64) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool8 > for bool32 { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool16> for bool32 { fn partial_cmp(&self, other: &bool16) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool64> for bool32 { fn partial_cmp(&self, other: &bool64) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool8 > for bool64 { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool16> for bool64 { fn partial_cmp(&self, other: &bool16) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+impl PartialOrd<bool32> for bool64 { fn partial_cmp(&self, other: &bool32) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), &bool::from(*other)) } }
+
+#[cfg(feature = "alloc")] mod _alloc {
+    use super::*;
+    use alloc::vec::Vec;
+
+    // DON'T IMPLEMENT (can't - orphan rules, as neither `Vec` nor `bool` are local types):
+    //  impl FromIterator<bool> for Vec<bool8> { ... }
+    //  impl Extend<bool> for Vec<bool8> { ... }
+    // Use `bool8::collect_vec` etc. instead.
+
+    // DON'T IMPLEMENT:
+    //  impl FromIterator<bool8> for Vec<bool  > { ... }
+    //  impl FromIterator<bool8> for Vec<bool32> { ... }
+    // A second `FromIterator<bool8>` impl makes `iter.collect::<Vec<_>>()` ambiguous (E0283) - in every crate, since features are additive.
+    // Use `.map(bool::from).collect()` or `.map(bool8::as_bool32).collect()` instead.
+    //
+    // DON'T IMPLEMENT:
+    //  impl Extend<bool8> for Vec<bool> { ... }
+    // Likewise, this makes `Vec::new().extend(flags)` ambiguous (E0282/E0283).  Use `bool8::extend_bools` etc. instead.
+
+    impl bool8 {
+        /// Collect canonical [bool8]s into a [Vec] - e.g. `bool8::collect_vec(flags.iter().copied())`.
+        ///
+        /// ```
+        /// # use abibool::*;
+        /// let flags = bool8::collect_vec([true, false]);
+        /// assert!(bool8::raw_eq_bytes(&flags, &[1, 0]));
+        ///
+        /// let plain : Vec<bool> = flags.iter().copied().map(bool::from).collect();
+        /// assert_eq!(plain, [true, false]);
+        /// ```
+        pub fn collect_vec(iter: impl IntoIterator<Item = bool>) -> Vec<bool8> { iter.into_iter().map(bool8::from).collect() }
+
+        /// Append the truthiness of each flag to a `Vec<bool>` - e.g. to accumulate flags from several FFI calls.
+        ///
+        /// ```
+        /// # use abibool::bool8;
+        /// let mut seen = vec![false];
+        /// bool8::extend_bools(&mut seen, [bool8::from_raw(0xff), bool8::FALSE]);
+        /// assert_eq!(seen, [false, true, false]);
+        /// ```
+        pub fn extend_bools(vec: &mut Vec<bool>, flags: impl IntoIterator<Item = bool8>) { vec.extend(flags.into_iter().map(bool::from)) }
 
-impl PartialOrd<bool  > for bool8  { fn partial_cmp(&self, other: &bool  ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), other) } }
-impl PartialOrd<bool  > for bool32 { fn partial_cmp(&self, other: &bool  ) -> Option<Ordering> { PartialOrd::partial_cmp(&bool::from(*self), other) } }
-impl PartialOrd<bool8 > for bool   { fn partial_cmp(&self, other: &bool8 ) -> Option<Ordering> { PartialOrd::partial_cmp(self, &bool::from(*other)) } }
-impl PartialOrd<bool32> for bool   { fn partial_cmp(&self, other: &bool32) -> Option<Ordering> { PartialOrd::partial_cmp(self, &bool::from(*other)) } }
+        /// Decode canonical `0`/`1` bytes, rejecting the first non-canonical byte as `Err((index, byte))` - e.g. for parsers of C output.
+        ///
+        /// ```
+        /// # use abibool::bool8;
+        /// assert_eq!(bool8::decode_strict(&[0, 1, 1]), Ok(vec![false, true, true]));
+        /// assert_eq!(bool8::decode_strict(&[0, 1, 0xff, 2]), Err((2, 0xff)));
+        /// ```
+        pub fn decode_strict(bytes: &[u8]) -> Result<Vec<bool>, (usize, u8)> {
+            bytes.iter().copied().enumerate().try_fold(Vec::with_capacity(bytes.len()), |mut vec, (i, b)| match b {
+                0 | 1   => { vec.push(b != 0); Ok(vec) },
+                _       => Err((i, b)),
+            })
+        }
 
-impl Ord for bool8  { fn cmp(&self, other: &bool8 ) -> Ordering { Ord::cmp(&bool::from(*self), &bool::from(*other)) } }
-impl Ord for bool32 { fn cmp(&self, other: &bool32) -> Ordering { Ord::cmp(&bool::from(*self), &bool::from(*other)) } }
+        /// Reinterpret a `Vec<u8>` as a `Vec<bool8>` in place, without reallocating or copying.
+        ///
+        /// ```
+        /// # use abibool::bool8;
+        /// let flags = bool8::vec_from_u8(vec![0, 1, 0xff]);
+        /// assert_eq!(flags, [false, true, true]);
+        /// assert_eq!(bool8::vec_into_u8(flags), [0, 1, 0xff]);
+        /// ```
+        pub fn vec_from_u8(vec: Vec<u8>) -> Vec<bool8> {
+            let mut vec = core::mem::ManuallyDrop::new(vec);
+            // SAFETY: bool8 is #[repr(transparent)] over u8, so the allocation's size and alignment are unchanged,
+            // and every u8 bit pattern is a valid bool8.  ManuallyDrop ensures the original Vec doesn't free the allocation.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        }
 
-impl Hash for bool8  { fn hash<H: Hasher>(&self, state: &mut H) { bool::from(*self).hash(state) } }
-impl Hash for bool32 { fn hash<H: Hasher>(&self, state: &mut H) { bool::from(*self).hash(state) } }
+        /// Reinterpret a `Vec<bool8>` as a `Vec<u8>` in place, without reallocating or copying.  Raw bit patterns are preserved.
+        ///
+        /// ```
+        /// # use abibool::bool8;
+        /// let flags = vec![bool8::TRUE, bool8::from_raw(0xff)];
+        /// assert_eq!(bool8::vec_into_u8(flags), [1, 0xff]);
+        /// ```
+        pub fn vec_into_u8(vec: Vec<bool8>) -> Vec<u8> {
+            let mut vec = core::mem::ManuallyDrop::new(vec);
+            // SAFETY: as per vec_from_u8 - and every bool8 is a valid u8.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        }
+    }
+
+    impl bool32 {
+        /// Collect canonical [bool32]s into a [Vec] - e.g. `bool32::collect_vec(flags.iter().copied())`.
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// let flags = bool32::collect_vec([true, false]);
+        /// assert!(bool32::raw_eq_i32s(&flags, &[1, 0]));
+        /// ```
+        pub fn collect_vec(iter: impl IntoIterator<Item = bool>) -> Vec<bool32> { iter.into_iter().map(bool32::from).collect() }
+
+        /// Append the truthiness of each flag to a `Vec<bool>` - see [bool8::extend_bools].
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// let mut seen = vec![false];
+        /// bool32::extend_bools(&mut seen, [bool32::from_raw(-1), bool32::FALSE]);
+        /// assert_eq!(seen, [false, true, false]);
+        /// ```
+        pub fn extend_bools(vec: &mut Vec<bool>, flags: impl IntoIterator<Item = bool32>) { vec.extend(flags.into_iter().map(bool::from)) }
+
+        /// Decode canonical `0`/`1` values, rejecting the first non-canonical value as `Err((index, value))` - see [bool8::decode_strict].
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// assert_eq!(bool32::decode_strict(&[1, 0]), Ok(vec![true, false]));
+        /// assert_eq!(bool32::decode_strict(&[1, -1, 2]), Err((1, -1)));
+        /// ```
+        pub fn decode_strict(values: &[i32]) -> Result<Vec<bool>, (usize, i32)> {
+            values.iter().copied().enumerate().try_fold(Vec::with_capacity(values.len()), |mut vec, (i, v)| match v {
+                0 | 1   => { vec.push(v != 0); Ok(vec) },
+                _       => Err((i, v)),
+            })
+        }
+
+        /// Reinterpret a `Vec<i32>` as a `Vec<bool32>` in place, without reallocating or copying.
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// let flags = bool32::vec_from_i32(vec![0, -1]);
+        /// assert_eq!(flags, [false, true]);
+        /// assert_eq!(bool32::vec_into_i32(flags), [0, -1]);
+        /// ```
+        pub fn vec_from_i32(vec: Vec<i32>) -> Vec<bool32> {
+            let mut vec = core::mem::ManuallyDrop::new(vec);
+            // SAFETY: bool32 is #[repr(transparent)] over i32, so the allocation's size and alignment are unchanged,
+            // and every i32 bit pattern is a valid bool32.  ManuallyDrop ensures the original Vec doesn't free the allocation.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        }
+
+        /// Reinterpret a `Vec<bool32>` as a `Vec<i32>` in place, without reallocating or copying.  Raw bit patterns are preserved.
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// let flags = vec![bool32::TRUE, bool32::from_raw(-1)];
+        /// assert_eq!(bool32::vec_into_i32(flags), [1, -1]);
+        /// ```
+        pub fn vec_into_i32(vec: Vec<bool32>) -> Vec<i32> {
+            let mut vec = core::mem::ManuallyDrop::new(vec);
+            // SAFETY: as per vec_from_i32 - and every bool32 is a valid i32.
+            unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")] mod _arbitrary {
+    use super::*;
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    // Drawn from the full integer range - not just `0`/`1` - so fuzzers exercise non-canonical truthy values like `0x80` too.
+    impl<'a> Arbitrary<'a> for bool8  { fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> { u8 ::arbitrary(u).map(Self) } fn size_hint(depth: usize) -> (usize, Option<usize>) { u8 ::size_hint(depth) } }
+    impl<'a> Arbitrary<'a> for bool16 { fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> { u16::arbitrary(u).map(Self) } fn size_hint(depth: usize) -> (usize, Option<usize>) { u16::size_hint(depth) } }
+    impl<'a> Arbitrary<'a> for bool32 { fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> { i32::arbitrary(u).map(Self) } fn size_hint(depth: usize) -> (usize, Option<usize>) { i32::size_hint(depth) } }
+    impl<'a> Arbitrary<'a> for bool64 { fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> { u64::arbitrary(u).map(Self) } fn size_hint(depth: usize) -> (usize, Option<usize>) { u64::size_hint(depth) } }
+}
+
+#[cfg(feature = "borsh")] mod _borsh {
+    use super::*;
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use borsh::io::{Read, Result, Write};
+
+    // All widths encode as a single canonical `0` or `1` byte.  Unlike borsh's own `bool`, any nonzero byte reads as true.
+    impl BorshSerialize   for bool8  { fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> { u8::serialize(&(bool::from(*self) as u8), writer) } }
+    impl BorshSerialize   for bool16 { fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> { u8::serialize(&(bool::from(*self) as u8), writer) } }
+    impl BorshSerialize   for bool32 { fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> { u8::serialize(&(bool::from(*self) as u8), writer) } }
+    impl BorshSerialize   for bool64 { fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> { u8::serialize(&(bool::from(*self) as u8), writer) } }
+    impl BorshDeserialize for bool8  { fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> { Ok(Self::from(u8::deserialize_reader(reader)? != 0)) } }
+    impl BorshDeserialize for bool16 { fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> { Ok(Self::from(u8::deserialize_reader(reader)? != 0)) } }
+    impl BorshDeserialize for bool32 { fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> { Ok(Self::from(u8::deserialize_reader(reader)? != 0)) } }
+    impl BorshDeserialize for bool64 { fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> { Ok(Self::from(u8::deserialize_reader(reader)? != 0)) } }
+}
 
 #[cfg(feature = "bytemuck")] mod _bytemuck {
     use super::*;
 
     unsafe impl bytemuck::Pod for bool8  {}
+    unsafe impl bytemuck::Pod for bool16 {}
     unsafe impl bytemuck::Pod for bool32 {}
+    unsafe impl bytemuck::Pod for bool64 {}
     unsafe impl bytemuck::Zeroable for bool8  {}
+    unsafe impl bytemuck::Zeroable for bool16 {}
     unsafe impl bytemuck::Zeroable for bool32 {}
+    unsafe impl bytemuck::Zeroable for bool64 {}
+}
+
+#[cfg(feature = "defmt")] mod _defmt {
+    use super::*;
+    use defmt::{Format, Formatter};
+
+    // Formatted as plain `bool`s, like Debug/Display.
+    impl Format for bool8  { fn format(&self, f: Formatter) { bool::from(*self).format(f) } }
+    impl Format for bool16 { fn format(&self, f: Formatter) { bool::from(*self).format(f) } }
+    impl Format for bool32 { fn format(&self, f: Formatter) { bool::from(*self).format(f) } }
+    impl Format for bool64 { fn format(&self, f: Formatter) { bool::from(*self).format(f) } }
 }
+
+#[cfg(feature = "heapless")] mod _heapless {
+    use super::*;
+    use heapless::Vec;
+
+    impl bool8 {
+        /// Collect canonical [bool8]s into a fixed-capacity [heapless::Vec], or return `Err(N + 1)` as soon as the iterator yields more than `N` (without draining the rest.)
+        ///
+        /// ```
+        /// # use abibool::bool8;
+        /// let flags = bool8::collect_heapless::<4>([true, false, true]).unwrap();
+        /// assert!(bool8::raw_eq_bytes(&flags, &[1, 0, 1]));
+        /// assert_eq!(bool8::collect_heapless::<2>([true, false, true, false]), Err(3)); // more than 2
+        /// ```
+        pub fn collect_heapless<const N: usize>(iter: impl IntoIterator<Item = bool>) -> Result<Vec<bool8, N>, usize> {
+            let mut vec = Vec::new();
+            for b in iter {
+                if vec.push(bool8::from(b)).is_err() { return Err(N + 1) }
+            }
+            Ok(vec)
+        }
+    }
+
+    impl bool32 {
+        /// Collect canonical [bool32]s into a fixed-capacity [heapless::Vec], or return `Err(N + 1)` as soon as the iterator yields more than `N` (without draining the rest.)
+        ///
+        /// ```
+        /// # use abibool::bool32;
+        /// let flags = bool32::collect_heapless::<2>([false, true]).unwrap();
+        /// assert!(bool32::raw_eq_i32s(&flags, &[0, 1]));
+        /// assert_eq!(bool32::collect_heapless::<1>(core::iter::repeat(true)), Err(2));
+        /// ```
+        pub fn collect_heapless<const N: usize>(iter: impl IntoIterator<Item = bool>) -> Result<Vec<bool32, N>, usize> {
+            let mut vec = Vec::new();
+            for b in iter {
+                if vec.push(bool32::from(b)).is_err() { return Err(N + 1) }
+            }
+            Ok(vec)
+        }
+    }
+}
+
+#[cfg(feature = "mlua")] mod _mlua {
+    use super::*;
+    use mlua::{FromLua, IntoLua, Lua, Result, Value};
+
+    // Lua booleans.  Reading uses Lua truthiness (only `nil` and `false` are falsey - even `0` is truthy), canonicalizing the result.
+    impl IntoLua for bool8  { fn into_lua(self, _lua: &Lua) -> Result<Value> { Ok(Value::Boolean(self.into())) } }
+    impl IntoLua for bool16 { fn into_lua(self, _lua: &Lua) -> Result<Value> { Ok(Value::Boolean(self.into())) } }
+    impl IntoLua for bool32 { fn into_lua(self, _lua: &Lua) -> Result<Value> { Ok(Value::Boolean(self.into())) } }
+    impl IntoLua for bool64 { fn into_lua(self, _lua: &Lua) -> Result<Value> { Ok(Value::Boolean(self.into())) } }
+    impl FromLua for bool8  { fn from_lua(value: Value, lua: &Lua) -> Result<Self> { bool::from_lua(value, lua).map(Self::from) } }
+    impl FromLua for bool16 { fn from_lua(value: Value, lua: &Lua) -> Result<Self> { bool::from_lua(value, lua).map(Self::from) } }
+    impl FromLua for bool32 { fn from_lua(value: Value, lua: &Lua) -> Result<Self> { bool::from_lua(value, lua).map(Self::from) } }
+    impl FromLua for bool64 { fn from_lua(value: Value, lua: &Lua) -> Result<Self> { bool::from_lua(value, lua).map(Self::from) } }
+}
+
+#[cfg(feature = "proptest")] pub use _proptest::{any_bool8, any_bool16, any_bool32, any_bool64};
+#[cfg(feature = "proptest")] mod _proptest {
+    use super::*;
+    use proptest::arbitrary::{any, Arbitrary};
+    use proptest::strategy::{Map, Strategy};
+
+    /// A [Strategy] for [bool8]s of any raw value - not just `0`/`1` - equivalent to `any::<bool8>()`.
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// use proptest::prelude::*;
+    /// proptest!(|(a in any_bool8(), b in any_bool8())| {
+    ///     prop_assert_eq!(a == b, bool::from(a) == bool::from(b));
+    /// });
+    /// ```
+    pub fn any_bool8() -> impl Strategy<Value = bool8> { any::<bool8>() }
+
+    /// A [Strategy] for [bool16]s of any raw value - see [any_bool8].
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// use proptest::prelude::*;
+    /// proptest!(|(a in any_bool16())| { prop_assert_eq!(a.normalized(), a); });
+    /// ```
+    pub fn any_bool16() -> impl Strategy<Value = bool16> { any::<bool16>() }
+
+    /// A [Strategy] for [bool32]s of any raw value - see [any_bool8].
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// use proptest::prelude::*;
+    /// proptest!(|(a in any_bool32(), b in any_bool8())| {
+    ///     prop_assert_eq!(a == b, bool::from(a) == bool::from(b));
+    /// });
+    /// ```
+    pub fn any_bool32() -> impl Strategy<Value = bool32> { any::<bool32>() }
+
+    /// A [Strategy] for [bool64]s of any raw value - see [any_bool8].
+    ///
+    /// ```
+    /// # use abibool::*;
+    /// use proptest::prelude::*;
+    /// proptest!(|(a in any_bool64())| { prop_assert_eq!(a.as_bool8(), a); });
+    /// ```
+    pub fn any_bool64() -> impl Strategy<Value = bool64> { any::<bool64>() }
+
+    // Drawn from the full integer range - not just `0`/`1` - so `any::<bool8>()` etc. exercise non-canonical truthy values like `0x80` too.
+    impl Arbitrary for bool8  { type Parameters = (); type Strategy = Map<proptest::num::u8 ::Any, fn(u8 ) -> Self>; fn arbitrary_with(_: ()) -> Self::Strategy { proptest::num::u8 ::ANY.prop_map(Self) } }
+    impl Arbitrary for bool16 { type Parameters = (); type Strategy = Map<proptest::num::u16::Any, fn(u16) -> Self>; fn arbitrary_with(_: ()) -> Self::Strategy { proptest::num::u16::ANY.prop_map(Self) } }
+    impl Arbitrary for bool32 { type Parameters = (); type Strategy = Map<proptest::num::i32::Any, fn(i32) -> Self>; fn arbitrary_with(_: ()) -> Self::Strategy { proptest::num::i32::ANY.prop_map(Self) } }
+    impl Arbitrary for bool64 { type Parameters = (); type Strategy = Map<proptest::num::u64::Any, fn(u64) -> Self>; fn arbitrary_with(_: ()) -> Self::Strategy { proptest::num::u64::ANY.prop_map(Self) } }
+}
+
+#[cfg(feature = "rkyv")] mod _rkyv {
+    use super::*;
+    use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+    use rkyv::bytecheck::CheckBytes;
+    use rkyv::rancor::Fallible;
+    use rkyv::traits::NoUndef;
+
+    // bool8 is its own archived form: a single byte, endian-independent, alignment 1, and every bit pattern is valid.
+    // The wider types archive as a canonical bool8 - archiving them as themselves would bake the host's endianness into the archive.
+    unsafe impl Portable for bool8 {}
+    unsafe impl NoUndef  for bool8 {}
+    unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for bool8 { unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> { Ok(()) } }
+
+    impl Archive for bool8  { type Archived = bool8; type Resolver = (); fn resolve(&self, _resolver: (), out: Place<bool8>) { out.write(*self) } }
+    impl Archive for bool16 { type Archived = bool8; type Resolver = (); fn resolve(&self, _resolver: (), out: Place<bool8>) { out.write(self.as_bool8()) } }
+    impl Archive for bool32 { type Archived = bool8; type Resolver = (); fn resolve(&self, _resolver: (), out: Place<bool8>) { out.write(self.as_bool8()) } }
+    impl Archive for bool64 { type Archived = bool8; type Resolver = (); fn resolve(&self, _resolver: (), out: Place<bool8>) { out.write(self.as_bool8()) } }
+    impl<S: Fallible + ?Sized> Serialize<S> for bool8  { fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> { Ok(()) } }
+    impl<S: Fallible + ?Sized> Serialize<S> for bool16 { fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> { Ok(()) } }
+    impl<S: Fallible + ?Sized> Serialize<S> for bool32 { fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> { Ok(()) } }
+    impl<S: Fallible + ?Sized> Serialize<S> for bool64 { fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> { Ok(()) } }
+    impl<D: Fallible + ?Sized> Deserialize<bool8,  D> for bool8 { fn deserialize(&self, _deserializer: &mut D) -> Result<bool8,  D::Error> { Ok(*self) } }
+    impl<D: Fallible + ?Sized> Deserialize<bool16, D> for bool8 { fn deserialize(&self, _deserializer: &mut D) -> Result<bool16, D::Error> { Ok(self.as_bool16()) } }
+    impl<D: Fallible + ?Sized> Deserialize<bool32, D> for bool8 { fn deserialize(&self, _deserializer: &mut D) -> Result<bool32, D::Error> { Ok(self.as_bool32()) } }
+    impl<D: Fallible + ?Sized> Deserialize<bool64, D> for bool8 { fn deserialize(&self, _deserializer: &mut D) -> Result<bool64, D::Error> { Ok(self.as_bool64()) } }
+}
+
+#[cfg(feature = "serde")] mod _serde {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    // (De)serialized as plain `bool`s.  Deserializing only accepts boolean tokens - not integers, which could hide data errors.
+    // No `deserialize_any` reliance, so these work within `#[serde(flatten)]`, and `#[serde(default)]` fields default to `FALSE`.
+    impl Serialize for bool8  { fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { bool::from(*self).serialize(serializer) } }
+    impl Serialize for bool16 { fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { bool::from(*self).serialize(serializer) } }
+    impl Serialize for bool32 { fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { bool::from(*self).serialize(serializer) } }
+    impl Serialize for bool64 { fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> { bool::from(*self).serialize(serializer) } }
+    impl<'de> Deserialize<'de> for bool8  { fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { bool::deserialize(deserializer).map(Self::from) } }
+    impl<'de> Deserialize<'de> for bool16 { fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { bool::deserialize(deserializer).map(Self::from) } }
+    impl<'de> Deserialize<'de> for bool32 { fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { bool::deserialize(deserializer).map(Self::from) } }
+    impl<'de> Deserialize<'de> for bool64 { fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { bool::deserialize(deserializer).map(Self::from) } }
+}
+
+#[cfg(feature = "schemars")] mod _schemars {
+    use super::*;
+    extern crate alloc;
+    use alloc::borrow::Cow;
+    use schemars::{JsonSchema, Schema, SchemaGenerator};
+
+    impl JsonSchema for bool8  { fn inline_schema() -> bool { true } fn schema_name() -> Cow<'static, str> { bool::schema_name() } fn json_schema(g: &mut SchemaGenerator) -> Schema { bool::json_schema(g) } }
+    impl JsonSchema for bool16 { fn inline_schema() -> bool { true } fn schema_name() -> Cow<'static, str> { bool::schema_name() } fn json_schema(g: &mut SchemaGenerator) -> Schema { bool::json_schema(g) } }
+    impl JsonSchema for bool32 { fn inline_schema() -> bool { true } fn schema_name() -> Cow<'static, str> { bool::schema_name() } fn json_schema(g: &mut SchemaGenerator) -> Schema { bool::json_schema(g) } }
+    impl JsonSchema for bool64 { fn inline_schema() -> bool { true } fn schema_name() -> Cow<'static, str> { bool::schema_name() } fn json_schema(g: &mut SchemaGenerator) -> Schema { bool::json_schema(g) } }
+}
+
+#[cfg(feature = "windows-result")] mod _windows_result {
+    use super::*;
+    use windows_result::BOOL;
+
+    // Raw conversions, without normalizing - like `From<i32>`.
+    // `windows-sys` and `winapi` use plain `i32`/`u8` aliases for BOOL/BOOLEAN, which already convert via `From<i32>` / `From<u8>`.
+    impl From<BOOL  > for bool32 { fn from(value: BOOL  ) -> Self { bool32(value.0) } }
+    impl From<bool32> for BOOL   { fn from(value: bool32) -> Self { BOOL(value.0) } }
+}
+
+#[cfg(test)] mod tests;
@@ -0,0 +1,1405 @@
+// Unit tests.  Doc examples show typical usage - these pin down edge cases, non-canonical inputs, and exact raw values.
+
+extern crate std;
+use super::*;
+use std::vec::Vec;
+
+#[test] fn normalize_iter() {
+    let raw = [0u8, 1, 2, 0x80, 0xff, 0];
+    let flags : Vec<bool8> = bool8::normalize_iter(raw.iter().copied()).collect();
+    assert!(bool8::raw_eq_bytes(&flags, &[0, 1, 1, 1, 1, 0]));
+
+    let flags : Vec<bool32> = bool32::normalize_iter([0, -1, 0x100, i32::MIN].iter().copied()).collect();
+    assert!(bool32::raw_eq_i32s(&flags, &[0, 1, 1, 1]));
+
+    assert_eq!(bool8::normalize_iter(core::iter::empty()).count(), 0);
+    assert_eq!(bool8::normalize_iter(core::iter::repeat(0x40)).take(3).map(bool8::into_raw).collect::<Vec<_>>(), [1, 1, 1]); // lazy
+}
+
+#[test] fn by_raw() {
+    use std::collections::HashSet;
+    use std::format;
+
+    let (a, b) = (bool8::from_raw(0xff), bool8::from_raw(0x01));
+    assert_ne!(ByRaw(a), ByRaw(b));
+    assert_eq!(ByRaw(b), ByRaw(bool8::TRUE));
+    assert_eq!(ByRaw(bool32::from_raw(-1)).cmp(&ByRaw(bool32::TRUE)), Ordering::Less);
+
+    let images : HashSet<_> = [a, b, bool8::TRUE, bool8::FALSE].iter().copied().map(ByRaw).collect();
+    assert_eq!(images.len(), 3);
+
+    assert_eq!(format!("{:?}", ByRaw(a)), "ByRaw(0xff)");
+    assert_eq!(format!("{:?}", ByRaw(bool16::from_raw(2))), "ByRaw(0x0002)");
+    assert_eq!(format!("{:?}", ByRaw(bool32::from_raw(-1))), "ByRaw(0xffffffff)");
+    assert_eq!(format!("{:?}", ByRaw(bool64::FALSE)), "ByRaw(0x0000000000000000)");
+    assert_eq!(format!("{:#?}", ByRaw(a)), "ByRaw(\n    0xff,\n)");
+}
+
+#[test] fn from_integer_literals() {
+    // Unsuffixed literals must keep inferring a single `From` impl.
+    assert_eq!(bool8::from(1).into_raw(), 1);
+    assert_eq!(bool32::from(-1).into_raw(), -1);
+    let b : bool8 = 2.into();
+    assert_eq!(b.into_raw(), 2);
+    let r : &bool8 = From::from(&0xff);
+    assert_eq!(r.into_raw(), 0xff);
+    let r : &bool32 = From::from(&1);
+    assert_eq!(r.into_raw(), 1);
+
+    assert_eq!(bool32::from(u32::MAX).into_raw(), -1);
+    assert_eq!(u32::from(bool32::from_raw(-1)), u32::MAX);
+    assert_eq!(*<&u32>::from(&bool32::from_raw(-2)), u32::MAX - 1);
+}
+
+#[test] fn from_bool_ref() {
+    // A view, not a copy - reading the `bool`'s byte through `&bool8` (checked by the miri CI job.)
+    let flags = [true, false, true];
+    let views : Vec<&bool8> = flags.iter().map(<&bool8>::from).collect();
+    assert_eq!(views.iter().map(|f| f.into_raw()).collect::<Vec<_>>(), [1, 0, 1]);
+    assert_eq!(views, [&bool8::TRUE, &bool8::FALSE, &bool8::TRUE]);
+    assert!(core::ptr::eq((views[1] as *const bool8).cast::<bool>(), &flags[1]));
+
+    let flag = true;
+    let view : &bool8 = From::from(&flag);
+    assert!(view.is_true());
+    assert_eq!(view.into_raw(), 1);
+}
+
+// `false`, canonical `true`, and a non-canonical `true` whose low byte is zero - to catch truncating comparisons.
+const B   : [bool  ; 2] = [false, true];
+const B8  : [bool8 ; 3] = [bool8 ::FALSE, bool8 ::TRUE, bool8 ::from_raw(0x80)];
+const B16 : [bool16; 3] = [bool16::FALSE, bool16::TRUE, bool16::from_raw(0x100)];
+const B32 : [bool32; 3] = [bool32::FALSE, bool32::TRUE, bool32::from_raw(i32::MIN)];
+const B64 : [bool64; 3] = [bool64::FALSE, bool64::TRUE, bool64::from_raw(1 << 32)];
+
+#[test] fn slice_eq() {
+    // core's element-wise slice/array PartialEq uses our truthiness based impls.
+    assert_eq!([bool8::from_raw(0xff)], [bool8::TRUE]);
+    assert_ne!([bool8::from_raw(0xff)], [bool8::FALSE]);
+    assert_eq!(&B8[..], &[bool8::FALSE, bool8::from_raw(2), bool8::from_raw(0xff)][..]);
+
+    let narrow : &[bool8 ] = &[bool8::from_raw(0x80), bool8::FALSE];
+    let wide   : &[bool32] = &[bool32::from_raw(-1), bool32::FALSE];
+    assert_eq!(narrow, wide);
+    assert_ne!(narrow, &wide[..1]); // lengths differ
+    assert_ne!(narrow, &[bool32::FALSE, bool32::FALSE][..]);
+}
+
+#[test] fn cross_width_ordering() {
+    fn check<A: PartialOrd<C> + Copy + Debug + Into<bool>, C: Copy + Debug + Into<bool>>(a: &[A], c: &[C]) {
+        for &a in a {
+            for &c in c {
+                let (ab, cb) = (a.into(), c.into());
+                assert_eq!(a == c, ab == cb, "{:?} == {:?}", a, c);
+                assert_eq!(a != c, ab != cb, "{:?} != {:?}", a, c);
+                assert_eq!(a.partial_cmp(&c), ab.partial_cmp(&cb), "{:?} <=> {:?}", a, c);
+                assert_eq!(a <  c, !ab &  cb, "{:?} < {:?}",  a, c);
+                assert_eq!(a >  c,  ab & !cb, "{:?} > {:?}",  a, c);
+                assert_eq!(a <= c, !ab |  cb, "{:?} <= {:?}", a, c);
+                assert_eq!(a >= c,  ab | !cb, "{:?} >= {:?}", a, c);
+            }
+        }
+    }
+
+    check(&B  , &B8 ); check(&B  , &B16); check(&B  , &B32); check(&B  , &B64);
+    check(&B8 , &B  ); check(&B8 , &B8 ); check(&B8 , &B16); check(&B8 , &B32); check(&B8 , &B64);
+    check(&B16, &B  ); check(&B16, &B8 ); check(&B16, &B16); check(&B16, &B32); check(&B16, &B64);
+    check(&B32, &B  ); check(&B32, &B8 ); check(&B32, &B16); check(&B32, &B32); check(&B32, &B64);
+    check(&B64, &B  ); check(&B64, &B8 ); check(&B64, &B16); check(&B64, &B32); check(&B64, &B64);
+
+    fn check_ord<T: Ord + Copy + Debug + Into<bool>>(values: &[T]) {
+        for &a in values {
+            for &b in values {
+                assert_eq!(a.cmp(&b), a.into().cmp(&b.into()), "{:?} cmp {:?}", a, b);
+                assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)), "{:?} <=> {:?}", a, b); // same-type PartialOrd must agree with Ord
+            }
+        }
+    }
+    check_ord(&B8); check_ord(&B16); check_ord(&B32); check_ord(&B64);
+}
+
+trait Raw : Copy { fn raw(self) -> u64; }
+impl Raw for bool   { fn raw(self) -> u64 { self as u64 } }
+impl Raw for bool8  { fn raw(self) -> u64 { self.into_raw() as u64 } }
+impl Raw for bool16 { fn raw(self) -> u64 { self.into_raw() as u64 } }
+impl Raw for bool32 { fn raw(self) -> u64 { self.into_raw() as u32 as u64 } }
+impl Raw for bool64 { fn raw(self) -> u64 { self.into_raw() } }
+
+#[test] fn bitxor() {
+    // The integer XOR trap: raw `0x03 ^ 0x01` would be the truthy `0x02`.
+    let (three, one, zero) = (bool8::from_raw(0x03), bool8::from_raw(0x01), bool8::FALSE);
+    assert_eq!((three ^ one).into_raw(), 0);
+    assert_eq!((three ^ zero).into_raw(), 1);
+    assert_eq!((zero ^ three).into_raw(), 1);
+    assert_eq!((zero ^ zero).into_raw(), 0);
+    assert_eq!((bool32::from_raw(-1) ^ bool32::from_raw(0x100)).into_raw(), 0);
+    assert_eq!((three ^ true).into_raw(), 0);
+
+    let mut flag = bool8::from_raw(0x80);
+    flag ^= bool8::from_raw(0x40);
+    assert_eq!(flag.into_raw(), 0);
+    flag ^= true;
+    assert_eq!(flag.into_raw(), 1);
+
+    fn check<A: core::ops::BitXor<C> + Raw + Debug + Into<bool>, C: Raw + Debug + Into<bool>>(a: &[A], c: &[C]) where A::Output : Raw {
+        for &a in a { for &c in c { assert_eq!((a ^ c).raw(), (a.into() ^ c.into()) as u64, "{:?} ^ {:?}", a, c); } }
+    }
+
+    check(&B8 , &B  ); check(&B8 , &B8 ); check(&B8 , &B16); check(&B8 , &B32); check(&B8 , &B64);
+    check(&B16, &B  ); check(&B16, &B8 ); check(&B16, &B16); check(&B16, &B32); check(&B16, &B64);
+    check(&B32, &B  ); check(&B32, &B8 ); check(&B32, &B16); check(&B32, &B32); check(&B32, &B64);
+    check(&B64, &B  ); check(&B64, &B8 ); check(&B64, &B16); check(&B64, &B32); check(&B64, &B64);
+}
+
+#[test] fn derived_debug() {
+    use std::format;
+
+    #[derive(Debug)] #[allow(dead_code)] struct Foo { a: bool8, b: bool32 }
+    let foo = Foo { a: bool8::from_raw(0xff), b: bool32::FALSE };
+    assert_eq!(format!("{:?}", foo), "Foo { a: true, b: false }");
+    assert_eq!(format!("{:#?}", foo), "Foo {\n    a: true,\n    b: false,\n}");
+    assert_eq!(format!("{:?}", [bool16::TRUE, bool16::from_raw(2)]), "[true, true]");
+    assert_eq!(format!("{:>5?}", bool64::TRUE), " true"); // padding flags pass through to bool's Debug
+}
+
+// Run under miri (see the CI workflow) to check the volatile and slice/Vec reinterpretation code for UB.
+#[test] fn volatile() {
+    let mut byte = 0x80u8;
+    let ptr : *mut u8 = &mut byte;
+    unsafe {
+        assert_eq!(bool8::read_volatile(ptr).into_raw(), 0x80); // reads as-is
+        bool8::write_volatile(ptr, bool8::from_raw(0x40));
+        assert_eq!(bool8::read_volatile(ptr).into_raw(), 1); // writes are canonical
+        bool8::write_volatile(ptr, bool8::FALSE);
+    }
+    assert_eq!(byte, 0);
+
+    let mut word = -1i32;
+    unsafe {
+        assert_eq!(bool32::read_volatile(&word).into_raw(), -1);
+        bool32::write_volatile(&mut word, bool32::from_raw(0x100));
+    }
+    assert_eq!(word, 1);
+
+    let mut region = [0u64; 2]; // e.g. shared memory
+    unsafe { bool64::write_volatile(region.as_mut_ptr().add(1), bool64::from_raw(u64::MAX)); }
+    assert_eq!(region, [0, 1]);
+}
+
+#[test] fn to_bool_checked() {
+    assert_eq!(bool8::FALSE.to_bool_checked(), (false, true));
+    assert_eq!(bool8::TRUE.to_bool_checked(), (true, true));
+    assert_eq!(bool8::from_raw(0x80).to_bool_checked(), (true, false));
+    assert_eq!(bool8::from_raw(0xff).to_bool_checked(), (true, false));
+
+    assert_eq!(bool32::FALSE.to_bool_checked(), (false, true));
+    assert_eq!(bool32::TRUE.to_bool_checked(), (true, true));
+    assert_eq!(bool32::from_raw(-1).to_bool_checked(), (true, false));
+    assert_eq!(bool32::from_raw(i32::MIN).to_bool_checked(), (true, false));
+}
+
+#[test] fn into_abi_bool() {
+    fn marshal(flag: impl IntoAbiBool + Copy) -> (u8, i32) { (flag.into_abi_u8(), flag.into_abi_i32()) }
+    assert_eq!(B  .map(marshal), [(0, 0), (1, 1)]);
+    assert_eq!(B8 .map(marshal), [(0, 0), (1, 1), (1, 1)]);
+    assert_eq!(B16.map(marshal), [(0, 0), (1, 1), (1, 1)]);
+    assert_eq!(B32.map(marshal), [(0, 0), (1, 1), (1, 1)]);
+    assert_eq!(B64.map(marshal), [(0, 0), (1, 1), (1, 1)]);
+    assert_eq!(marshal(bool32::from_raw(-1)), (1, 1));
+}
+
+#[test] fn ok_if_true() {
+    assert_eq!(bool32::TRUE.ok_if_true(), Ok(()));
+    assert_eq!(bool32::from_raw(-1).ok_if_true(), Ok(()));
+    assert_eq!(bool32::from_raw(i32::MIN).ok_if_true(), Ok(()));
+    assert_eq!(bool32::FALSE.ok_if_true(), Err(()));
+}
+
+#[test] fn to_c_predicate_return() {
+    assert_eq!(bool32::to_c_predicate_return(true).into_raw(), 1); // exactly `1`, not just truthy
+    assert_eq!(bool32::to_c_predicate_return(false).into_raw(), 0);
+
+    extern "C" fn is_even(n: i32) -> bool32 { bool32::to_c_predicate_return(n % 2 == 0) }
+    assert_eq!([2, 3, -4].map(|n| is_even(n).into_raw()), [1, 0, 1]);
+}
+
+#[test] fn as_ref_bytes() {
+    let flag = bool8::from_raw(0x80);
+    let bytes = AsRef::<[u8]>::as_ref(&flag);
+    assert_eq!(bytes.len(), 1);
+    assert_eq!(bytes, [0x80]); // raw, not canonicalized
+    assert_eq!(bytes.as_ptr(), flag.as_ptr()); // a view, not a copy
+    assert_eq!(AsRef::<[u8]>::as_ref(&bool8::TRUE), [1]);
+    assert_eq!(AsRef::<[u8]>::as_ref(&bool8::FALSE), [0]);
+}
+
+#[test] fn network_byte_order() {
+    assert_eq!(bool32::TRUE.to_network(), [0, 0, 0, 1]);
+    assert_eq!(bool32::FALSE.to_network(), [0, 0, 0, 0]);
+    assert_eq!(bool32::from_raw(-1).to_network(), [0, 0, 0, 1]); // canonical on the wire
+    assert_eq!(bool32::from_raw(0x100).to_network(), [0, 0, 0, 1]);
+
+    assert_eq!(bool32::from_network([0, 0, 0, 1]).into_raw(), 1);
+    assert_eq!(bool32::from_network([0, 0, 0, 0]).into_raw(), 0);
+    assert_eq!(bool32::from_network([1, 0, 0, 0]).into_raw(), 0x0100_0000); // big-endian, regardless of host
+
+    for &b in &B32 { assert_eq!(bool32::from_network(b.to_network()).into_raw(), b.is_true() as i32); }
+}
+
+#[test] fn bit() {
+    const FLAGS : u32 = 0x8000_0005;
+    const BITS  : [bool8; 6] = [bool8::bit(FLAGS, 0), bool8::bit(FLAGS, 1), bool8::bit(FLAGS, 2), bool8::bit(FLAGS, 30), bool8::bit(FLAGS, 31), bool8::bit(FLAGS, 32)];
+    assert!(bool8::raw_eq_bytes(&BITS, &[1, 0, 1, 0, 1, 0]));
+
+    const HIGH : bool32 = bool32::bit(u32::MAX, 31);
+    assert_eq!(HIGH.into_raw(), 1); // canonical, not the masked bit
+
+    // Out of range bits read as `false` instead of overflowing the shift.
+    const OUT : [bool64; 3] = [bool64::bit(u32::MAX, 32), bool64::bit(u32::MAX, 33), bool64::bit(u32::MAX, u32::MAX)];
+    assert_eq!(OUT.map(bool64::into_raw), [0, 0, 0]);
+}
+
+#[test] fn true_count() {
+    let mut count = TrueCount::default();
+    count += bool8::from_raw(0x80);
+    count += bool8::FALSE;
+    count += bool16::from_raw(0x100);
+    count += bool32::from_raw(i32::MIN);
+    count += bool32::FALSE;
+    count += bool64::from_raw(1 << 32);
+    count += true;
+    count += false;
+    assert_eq!(count, TrueCount(5));
+    assert_eq!(usize::from(count), 5);
+}
+
+#[test] fn cmp_raw() {
+    let mut captured = [bool8::from_raw(0xff), bool8::TRUE, bool8::from_raw(0x80), bool8::FALSE, bool8::from_raw(0x80), bool8::TRUE].to_vec();
+    captured.sort_by(|a, b| a.cmp_raw(*b));
+    assert!(bool8::raw_eq_bytes(&captured, &[0, 1, 1, 0x80, 0x80, 0xff]));
+    captured.dedup_by(|a, b| a.cmp_raw(*b) == Ordering::Equal);
+    assert!(bool8::raw_eq_bytes(&captured, &[0, 1, 0x80, 0xff]));
+
+    assert_eq!(bool8::TRUE.cmp_raw(bool8::from_raw(0x80)), Ordering::Less);
+    assert_eq!(bool8::TRUE.cmp(&bool8::from_raw(0x80)), Ordering::Equal); // unlike truthiness
+    assert_eq!(bool32::from_raw(-1).cmp_raw(bool32::TRUE), Ordering::Less); // signed, like the underlying i32
+    assert_eq!(bool64::from_raw(1 << 32).cmp_raw(bool64::from_raw(1 << 32)), Ordering::Equal);
+}
+
+#[test] fn as_u8() {
+    assert_eq!(bool32::from_raw(0x100).as_u8(), 1); // truthiness, not `0x100 as u8`
+    assert_eq!(bool32::from_raw(0x100).low_byte(), 0);
+    assert_eq!(bool32::from_raw(i32::MIN).as_u8(), 1);
+    assert_eq!(bool32::from_raw(-1).as_u8(), 1);
+    assert_eq!(bool32::TRUE.as_u8(), 1);
+    assert_eq!(bool32::FALSE.as_u8(), 0);
+}
+
+#[test] fn bitand() {
+    // The integer AND trap: raw `0x80 & 0x40` would be the falsey `0x00`.
+    let both = bool8::from_raw(0x80) & bool8::from_raw(0x40);
+    assert_eq!(both, bool8::TRUE);
+    assert_eq!(both.into_raw(), 1);
+
+    let mut flag = bool8::from_raw(0x80);
+    flag &= bool8::from_raw(0x40);
+    assert_eq!(flag.into_raw(), 1);
+
+    assert_eq!((bool32::from_raw(0x100) & bool32::from_raw(0x200)).into_raw(), 1);
+    assert_eq!((bool8::from_raw(0x80) & bool32::from_raw(0x100)).into_raw(), 1);
+    assert_eq!((bool8::from_raw(0x80) & bool8::FALSE).into_raw(), 0);
+    assert_eq!((bool8::from_raw(0x80) & true).into_raw(), 1);
+}
+
+#[test] fn bitor() {
+    // The integer OR trap: raw `0x40 | 0x01` would be the non-canonical `0x41`.
+    #[repr(C)] struct Status { any_error: bool8, any_warning: bool32 }
+    let mut status = Status { any_error: bool8::from_raw(0x40), any_warning: bool32::from_raw(0x100) };
+    status.any_error |= true;
+    assert_eq!(status.any_error.into_raw(), 1);
+    status.any_error = bool8::from_raw(0x40);
+    status.any_error |= false;
+    assert_eq!(status.any_error.into_raw(), 1); // canonicalized even when the RHS doesn't change truthiness
+    status.any_warning |= false;
+    assert_eq!(status.any_warning.into_raw(), 1);
+
+    let mut flag = bool8::FALSE;
+    flag |= false;
+    assert_eq!(flag.into_raw(), 0);
+    flag |= bool8::from_raw(0x80);
+    assert_eq!(flag.into_raw(), 1);
+
+    assert_eq!((bool8::from_raw(0x40) | bool8::from_raw(0x01)).into_raw(), 1);
+    assert_eq!((bool8::FALSE | bool8::FALSE).into_raw(), 0);
+    assert_eq!((bool8::FALSE | true).into_raw(), 1);
+    assert_eq!((bool32::from_raw(-1) | false).into_raw(), 1);
+    assert_eq!((bool8::FALSE | bool64::from_raw(1 << 32)).into_raw(), 1);
+}
+
+#[test] fn splat() {
+    assert_eq!(bool8::splat::<0>(true).map(bool8::into_raw), [0u8; 0]);
+    assert_eq!(bool8::splat::<1>(true).map(bool8::into_raw), [1]);
+    assert_eq!(bool8::splat::<4>(false).map(bool8::into_raw), [0; 4]);
+    assert_eq!(bool32::splat::<3>(bool8::from_raw(0x80)).map(bool32::into_raw), [1; 3]); // canonicalized
+    assert_eq!(bool64::splat::<16>(true).map(bool64::into_raw), [1; 16]);
+}
+
+#[test] fn canonicalize_array() {
+    const TABLE : [bool8; 5] = bool8::canonicalize_array([bool8::FALSE, bool8::TRUE, bool8::from_raw(2), bool8::from_raw(0x80), bool8::from_raw(0xff)]);
+    assert!(bool8::raw_eq_bytes(&TABLE, &[0, 1, 1, 1, 1]));
+
+    const WIDE : [bool32; 3] = bool32::canonicalize_array([bool32::from_raw(-1), bool32::FALSE, bool32::from_raw(i32::MIN)]);
+    assert!(bool32::raw_eq_i32s(&WIDE, &[1, 0, 1]));
+
+    const EMPTY : [bool8; 0] = bool8::canonicalize_array([]);
+    assert_eq!(EMPTY.len(), 0);
+}
+
+#[test] fn all_of_any_of() {
+    assert_eq!(bool8::all_of(&[]).into_raw(), 1);
+    assert_eq!(bool8::any_of(&[]).into_raw(), 0);
+
+    let all_true = [bool8::TRUE, bool8::from_raw(0x80)];
+    assert_eq!(bool8::all_of(&all_true).into_raw(), 1);
+    assert_eq!(bool8::any_of(&all_true).into_raw(), 1);
+
+    let all_false = [bool8::FALSE; 3];
+    assert_eq!(bool8::all_of(&all_false).into_raw(), 0);
+    assert_eq!(bool8::any_of(&all_false).into_raw(), 0);
+
+    let mixed = [bool8::FALSE, bool8::from_raw(0xff)];
+    assert_eq!(bool8::all_of(&mixed).into_raw(), 0);
+    assert_eq!(bool8::any_of(&mixed).into_raw(), 1); // canonical, not 0xff
+
+    assert_eq!(bool32::all_of(&B32).into_raw(), 0);
+    assert_eq!(bool32::all_of(&B32[1..]).into_raw(), 1);
+    assert_eq!(bool32::any_of(&B32[..1]).into_raw(), 0);
+}
+
+#[test] fn write_csv_flags() {
+    use std::string::String;
+
+    let mut row = String::new();
+    bool8::write_csv_flags(&[bool8::TRUE, bool8::FALSE, bool8::from_raw(0xff)], &mut row).unwrap();
+    assert_eq!(row, "1,0,1");
+
+    let mut row = String::new();
+    bool8::write_csv_flags(&[], &mut row).unwrap();
+    assert_eq!(row, "");
+
+    let mut row = String::new();
+    bool8::write_csv_flags(&[bool8::FALSE], &mut row).unwrap();
+    assert_eq!(row, "0");
+
+    let mut row = String::new();
+    bool32::write_csv_flags(&B32, &mut row).unwrap();
+    assert_eq!(row, "0,1,1");
+}
+
+#[test] fn from_fn() {
+    let even = bool8::from_fn::<6>(|i| i % 2 == 0);
+    assert!(bool8::raw_eq_bytes(&even, &[1, 0, 1, 0, 1, 0]));
+
+    let even = bool32::from_fn::<5>(|i| i % 2 == 0);
+    assert!(bool32::raw_eq_i32s(&even, &[1, 0, 1, 0, 1]));
+
+    let mut calls = Vec::new();
+    let _ = bool16::from_fn::<3>(|i| { calls.push(i); true });
+    assert_eq!(calls, [0, 1, 2]); // in order, once each
+
+    assert_eq!(bool8::from_fn::<0>(|_| unreachable!()).len(), 0);
+}
+
+#[test] fn as_array() {
+    let flag = bool8::from_raw(0x80);
+    assert_eq!(flag.as_array().map(bool8::into_raw), [0x80]);
+    assert_eq!(flag.as_byte_array(), [0x80]);
+
+    assert_eq!(bool32::TRUE.as_array().map(bool32::into_raw), [1]);
+    assert_eq!(bool32::TRUE.as_byte_array(), 1i32.to_ne_bytes());
+    assert_eq!(bool32::from_raw(-1).as_byte_array(), [0xff; 4]);
+    assert_eq!(bool64::from_raw(1 << 32).as_byte_array(), (1u64 << 32).to_ne_bytes());
+}
+
+#[test] fn write_named_flags() {
+    use std::string::String;
+
+    let mut out = String::new();
+    bool8::write_named_flags(&["enabled", "visible"], &[bool8::from_raw(0xff), bool8::FALSE], &mut out).unwrap();
+    assert_eq!(out, "enabled=true\nvisible=false\n");
+
+    let mut out = String::new();
+    assert!(bool8::write_named_flags(&["enabled"], &[bool8::TRUE, bool8::TRUE], &mut out).is_err());
+    assert!(bool32::write_named_flags(&["enabled", "visible"], &[bool32::TRUE], &mut out).is_err());
+    assert_eq!(out, ""); // nothing written on mismatch
+
+    bool8::write_named_flags(&[], &[], &mut out).unwrap();
+    assert_eq!(out, "");
+}
+
+#[test] fn from_predicate() {
+    let (len, enabled) = (3, true);
+    assert_eq!(bool8::from_predicate(|| len > 0 && enabled).into_raw(), 1);
+    assert_eq!(bool8::from_predicate(|| len > 5 && enabled).into_raw(), 0);
+    assert_eq!(bool32::from_predicate(|| true).into_raw(), 1);
+    assert_eq!(bool32::from_predicate(|| false).into_raw(), 0);
+
+    let mut calls = 0;
+    let _ = bool64::from_predicate(|| { calls += 1; false });
+    assert_eq!(calls, 1);
+}
+
+#[test] fn from_sign() {
+    assert_eq!([-1, 0, 1].map(bool8::from_sign).map(bool8::into_raw), [1, 0, 1]);
+    assert_eq!([i32::MIN, -42, 0, 42, i32::MAX].map(bool32::from_sign).map(bool32::into_raw), [1, 1, 0, 1, 1]);
+    assert!(bool8::from_sign("a".cmp("b") as i32).is_true()); // e.g. a strcmp result
+}
+
+#[test] fn stable_key() {
+    assert_eq!(bool8::TRUE.stable_key(), "true");
+    assert_eq!(bool8::FALSE.stable_key(), "false");
+    assert_eq!(bool8::from_raw(0xff).stable_key(), "true");
+    assert_eq!(bool32::from_raw(i32::MIN).stable_key(), "true");
+    assert_eq!(bool32::FALSE.stable_key(), "false");
+    assert_eq!(B64.map(bool64::stable_key), ["false", "true", "true"]);
+}
+
+#[test] fn sort_key() {
+    let mut flags = [bool8::from_raw(0xff), bool8::FALSE, bool8::from_raw(0x80), bool8::TRUE, bool8::FALSE, bool8::from_raw(2)].to_vec();
+    flags.sort_by_key(|f| f.sort_key());
+    assert!(bool8::raw_eq_bytes(&flags, &[0, 0, 0xff, 0x80, 1, 2])); // grouped by truthiness, raw order kept within groups
+    assert_eq!(B32.map(bool32::sort_key), [0, 1, 1]);
+}
+
+#[test] fn display_with() {
+    use std::format;
+
+    struct YesNo;
+    impl BoolLabels for YesNo {
+        fn true_label(&self)  -> &str { "Yes" }
+        fn false_label(&self) -> &str { "No" }
+    }
+
+    assert_eq!(format!("{}", bool8::from_raw(0x80).display_with(&YesNo)), "Yes");
+    assert_eq!(format!("{}", bool8::FALSE.display_with(&YesNo)), "No");
+    assert_eq!(format!("{}", bool32::from_raw(-1).display_with(&YesNo)), "Yes");
+    assert_eq!(format!("[{:<4}]", bool8::TRUE.display_with(&YesNo)), "[Yes ]");
+    assert_eq!(format!("[{:>3}]", bool64::FALSE.display_with(&YesNo)), "[ No]");
+}
+
+#[test] fn expect_canonical() {
+    assert!( bool8 ::TRUE .expect_canonical("caps.enabled"));
+    assert!(!bool8 ::FALSE.expect_canonical("caps.enabled"));
+    assert!( bool32::TRUE .expect_canonical("caps.enabled"));
+    assert!(!bool32::FALSE.expect_canonical("caps.enabled"));
+}
+
+#[test] #[should_panic(expected = "caps.enabled: non-canonical raw value 0xff")] fn expect_canonical_bool8() {
+    bool8::from_raw(0xff).expect_canonical("caps.enabled");
+}
+
+#[test] #[should_panic(expected = "caps.enabled: non-canonical raw value 0xffffffff")] fn expect_canonical_bool32() {
+    bool32::from_raw(-1).expect_canonical("caps.enabled");
+}
+
+#[test] fn try_from_raw() {
+    assert_eq!(bool8 ::try_from_raw(0).map(bool8 ::into_raw), Ok(0));
+    assert_eq!(bool8 ::try_from_raw(1).map(bool8 ::into_raw), Ok(1));
+    assert_eq!(bool16::try_from_raw(0).map(bool16::into_raw), Ok(0));
+    assert_eq!(bool16::try_from_raw(1).map(bool16::into_raw), Ok(1));
+    assert_eq!(bool32::try_from_raw(0).map(bool32::into_raw), Ok(0));
+    assert_eq!(bool32::try_from_raw(1).map(bool32::into_raw), Ok(1));
+    assert_eq!(bool64::try_from_raw(0).map(bool64::into_raw), Ok(0));
+    assert_eq!(bool64::try_from_raw(1).map(bool64::into_raw), Ok(1));
+
+    // The error carries the raw value as-is.
+    assert_eq!(bool8 ::try_from_raw(2         ).map(bool8 ::into_raw), Err(NonCanonicalError(2)));
+    assert_eq!(bool8 ::try_from_raw(0xff      ).map(bool8 ::into_raw), Err(NonCanonicalError(0xff)));
+    assert_eq!(bool16::try_from_raw(0x100     ).map(bool16::into_raw), Err(NonCanonicalError(0x100)));
+    assert_eq!(bool32::try_from_raw(2         ).map(bool32::into_raw), Err(NonCanonicalError(2)));
+    assert_eq!(bool32::try_from_raw(-1        ).map(bool32::into_raw), Err(NonCanonicalError(-1)));
+    assert_eq!(bool64::try_from_raw(u64::MAX  ).map(bool64::into_raw), Err(NonCanonicalError(u64::MAX)));
+
+    assert_eq!(std::format!("{}", bool8 ::try_from_raw(2   ).unwrap_err()), "non-canonical boolean value 0x2");
+    assert_eq!(std::format!("{}", bool8 ::try_from_raw(0xff).unwrap_err()), "non-canonical boolean value 0xff");
+    assert_eq!(std::format!("{}", bool32::try_from_raw(-1  ).unwrap_err()), "non-canonical boolean value 0xffffffff");
+}
+
+#[test] fn from_str() {
+    for (s, expected) in [("true", true), ("1", true), ("false", false), ("0", false)] {
+        assert_eq!(s.parse::<bool8 >().map(bool8 ::into_raw), Ok(expected as u8 ), "{:?}", s);
+        assert_eq!(s.parse::<bool16>().map(bool16::into_raw), Ok(expected as u16), "{:?}", s);
+        assert_eq!(s.parse::<bool32>().map(bool32::into_raw), Ok(expected as i32), "{:?}", s);
+        assert_eq!(s.parse::<bool64>().map(bool64::into_raw), Ok(expected as u64), "{:?}", s);
+    }
+
+    for s in ["", "TRUE", "False", " 1", "1 ", "2", "-1", "01", "yes"] {
+        assert!(s.parse::<bool8 >().is_err(), "{:?}", s);
+        assert!(s.parse::<bool16>().is_err(), "{:?}", s);
+        assert!(s.parse::<bool32>().is_err(), "{:?}", s);
+        assert!(s.parse::<bool64>().is_err(), "{:?}", s);
+    }
+
+    let err = "TRUE".parse::<bool8>().unwrap_err();
+    assert_eq!(err, "".parse::<bool32>().unwrap_err());
+    assert_eq!(std::format!("{}", err), "provided string was not `true`, `false`, `1`, or `0`");
+}
+
+#[test] fn assert_no_padding() {
+    use core::mem::size_of;
+    #[repr(C)] struct Caps { a: bool32, b: bool32, c: bool8, d: bool8, e: [bool8; 2] }
+    const _ : () = crate::assert_no_padding::<{size_of::<Caps>()}, {2 * size_of::<bool32>() + 4 * size_of::<bool8>()}>();
+    crate::assert_no_padding::<{size_of::<Caps>()}, {2 * size_of::<bool32>() + 4 * size_of::<bool8>()}>(); // also callable at runtime
+}
+
+// At compile time, padding fails the build - see the `compile_fail` doc example on `assert_no_padding`.
+#[test] #[should_panic(expected = "struct has padding")] fn assert_no_padding_padded() {
+    use core::mem::size_of;
+    #[repr(C)] struct Padded { a: bool8, b: bool32 }
+    crate::assert_no_padding::<{size_of::<Padded>()}, {size_of::<bool8>() + size_of::<bool32>()}>();
+}
+
+#[test] fn to_c_bool_int() {
+    assert_eq!(B8 .map(bool8 ::to_c_bool_int), [0, 1, 1]);
+    assert_eq!(B16.map(bool16::to_c_bool_int), [0, 1, 1]);
+    assert_eq!(B32.map(bool32::to_c_bool_int), [0, 1, 1]);
+    assert_eq!(B64.map(bool64::to_c_bool_int), [0, 1, 1]);
+    assert_eq!(bool8::from_raw(0xff).to_c_bool_int(), 1);
+    assert_eq!(bool32::from_raw(-1).to_c_bool_int(), 1);
+}
+
+#[test] fn diag() {
+    use std::format;
+
+    assert_eq!(format!("{:?}", Diag(bool8::from_raw(0xff))), "Bool8 { truthy: true, raw: 0xff }");
+    assert_eq!(format!("{:?}", Diag(bool8::FALSE)), "Bool8 { truthy: false, raw: 0x00 }");
+    assert_eq!(format!("{:?}", Diag(bool16::from_raw(0x100))), "Bool16 { truthy: true, raw: 0x0100 }");
+    assert_eq!(format!("{:?}", Diag(bool32::from_raw(-1))), "Bool32 { truthy: true, raw: 0xffffffff }");
+    assert_eq!(format!("{:?}", Diag(bool64::from_raw(1 << 32))), "Bool64 { truthy: true, raw: 0x0000000100000000 }");
+    assert_eq!(format!("{:#?}", Diag(bool8::TRUE)), "Bool8 {\n    truthy: true,\n    raw: 0x01,\n}");
+
+    #[derive(Debug)] #[allow(dead_code)] struct Caps { enabled: Diag<bool8>, visible: bool32 }
+    let caps = Caps { enabled: Diag(bool8::from_raw(0x80)), visible: bool32::TRUE };
+    assert_eq!(format!("{:?}", caps), "Caps { enabled: Bool8 { truthy: true, raw: 0x80 }, visible: true }");
+    assert_eq!(format!("{:#?}", caps), "Caps {\n    enabled: Bool8 {\n        truthy: true,\n        raw: 0x80,\n    },\n    visible: true,\n}");
+}
+
+#[test] fn raw_eq() {
+    let decoded = [bool8::TRUE, bool8::from_raw(0xff), bool8::FALSE];
+    assert!( bool8::raw_eq_bytes(&decoded, &[1, 0xff, 0]));
+    assert!(!bool8::raw_eq_bytes(&decoded, &[1, 1, 0])); // same truthiness, different bytes
+    assert!(!bool8::raw_eq_bytes(&decoded, &[1, 0xff]));
+    assert!(!bool8::raw_eq_bytes(&decoded, &[1, 0xff, 0, 0]));
+    assert!( bool8::raw_eq_bytes(&[], &[]));
+
+    let decoded = [bool32::from_raw(-1), bool32::FALSE];
+    assert!( bool32::raw_eq_i32s(&decoded, &[-1, 0]));
+    assert!(!bool32::raw_eq_i32s(&decoded, &[1, 0]));
+    assert!(!bool32::raw_eq_i32s(&decoded, &[-1]));
+}
+
+#[test] fn bool_array() {
+    use core::mem::{align_of, size_of};
+    assert_eq!(size_of::<BoolArray<0>>(), 0);
+    assert_eq!(size_of::<BoolArray<1>>(), 1);
+    assert_eq!(size_of::<BoolArray<8>>(), 8);
+    assert_eq!(size_of::<BoolArray<13>>(), 13);
+    assert_eq!(align_of::<BoolArray<8>>(), 1);
+
+    let mut flags = BoolArray([bool8::from_raw(0x80), bool8::FALSE, bool8::from_raw(0xff), bool8::TRUE]);
+    assert!(flags.any() && !flags.all());
+    assert_eq!(flags.count(), 3);
+    assert_eq!((flags[0], flags[1], flags.get(2), flags.get(4)), (true, false, Some(true), None));
+
+    flags.normalize();
+    assert!(bool8::raw_eq_bytes(&flags.0, &[1, 0, 1, 1]));
+    flags.toggle();
+    assert!(bool8::raw_eq_bytes(&flags.0, &[0, 1, 0, 0]));
+    assert_eq!(flags.count(), 1);
+    flags.set(3, true);
+    assert!(bool8::raw_eq_bytes(&flags.0, &[0, 1, 0, 1]));
+
+    let empty = BoolArray::<0>::default();
+    assert!(empty.all() && !empty.any() && empty.count() == 0);
+    assert!(BoolArray::<5>::TRUE.all() && !BoolArray::<5>::FALSE.any());
+}
+
+#[test] #[should_panic(expected = "index out of bounds")] fn bool_array_index_out_of_bounds() {
+    let flags = BoolArray::<2>::FALSE;
+    let _ = flags[core::hint::black_box(2)];
+}
+
+#[test] #[should_panic(expected = "index out of bounds")] fn bool_array_set_out_of_bounds() {
+    let mut flags = BoolArray::<2>::FALSE;
+    flags.set(core::hint::black_box(2), true);
+}
+
+#[test] fn from_count() {
+    assert_eq!(bool8::from_count(0).into_raw(), 0);
+    assert_eq!([1, 2, 255, 256, usize::MAX].map(bool8::from_count).map(bool8::into_raw), [1; 5]); // not truncated
+    assert_eq!(bool32::from_count(0).into_raw(), 0);
+    assert_eq!(bool32::from_count(usize::MAX).into_raw(), 1);
+
+    let matches = [3, 8, 5].iter().filter(|&&n| n > 4).count();
+    assert!(bool32::from_count(matches).is_true());
+}
+
+#[test] fn ok_or() {
+    #[derive(Debug, PartialEq)] struct Failed(&'static str);
+    assert_eq!(bool32::TRUE.ok_or(Failed("SetWindowTextW")), Ok(()));
+    assert_eq!(bool32::from_raw(-1).ok_or(Failed("SetWindowTextW")), Ok(()));
+    assert_eq!(bool32::FALSE.ok_or(Failed("SetWindowTextW")), Err(Failed("SetWindowTextW")));
+    assert_eq!(bool8::from_raw(0x80).ok_or(1), Ok(()));
+    assert_eq!(bool8::FALSE.ok_or(1), Err(1));
+
+    let mut calls = 0;
+    assert_eq!(bool32::TRUE.ok_or_else(|| { calls += 1; calls }), Ok(()));
+    assert_eq!(calls, 0); // lazy
+    assert_eq!(bool32::FALSE.ok_or_else(|| { calls += 1; calls }), Err(1));
+    assert_eq!(bool64::FALSE.ok_or_else(|| "failed"), Err("failed"));
+}
+
+#[test] fn map_or_unwrap_or() {
+    assert_eq!(bool8::from_raw(0x80).map_or(0, || 42), 42);
+    assert_eq!(bool32::from_raw(-1).map_or("off", || "on"), "on");
+
+    let mut calls = 0;
+    assert_eq!(bool8::FALSE.map_or(7, || { calls += 1; 42 }), 7);
+    assert_eq!(bool64::FALSE.map_or(7, || { calls += 1; 42 }), 7);
+    assert_eq!(calls, 0); // lazy
+
+    assert_eq!(bool8::from_raw(0xff).unwrap_or("on", "off"), "on");
+    assert_eq!(bool8::FALSE.unwrap_or("on", "off"), "off");
+    assert_eq!(bool32::from_raw(i32::MIN).unwrap_or(1, 2), 1);
+    assert_eq!(bool16::FALSE.unwrap_or(1, 2), 2);
+}
+
+#[test] fn toggled() {
+    let flag = bool8::from_raw(0x80);
+    assert_eq!(flag.toggled().into_raw(), 0);
+    assert_eq!(flag.toggled().toggled().into_raw(), 1); // canonical, not back to 0x80
+    assert_eq!(B32.map(bool32::toggled).map(bool32::into_raw), [1, 0, 0]);
+    assert_eq!(B64.map(bool64::toggled).map(bool64::into_raw), [1, 0, 0]);
+
+    #[repr(C)] struct Caps { enabled: bool8, visible: bool32 }
+    let mut caps = Caps { enabled: bool8::from_raw(0xff), visible: bool32::FALSE };
+    caps.enabled.toggle();
+    caps.visible.toggle();
+    assert_eq!((caps.enabled.into_raw(), caps.visible.into_raw()), (0, 1));
+    caps.enabled.toggle();
+    assert_eq!(caps.enabled.into_raw(), 1);
+}
+
+#[test] fn array_helpers() {
+    let raw = [0u8, 1, 2, 0x80, 0xff, 0, 1, 0];
+    let flags = bool8::from_u8_array(raw);
+    assert_eq!(flags.map(bool::from), [false, true, true, true, true, false, true, false]);
+    assert_eq!(bool8::into_u8_array(flags), raw); // raw bits preserved
+    let flags = bool8::from_u8_array_ref(&raw);
+    assert_eq!(flags.as_ptr() as usize, raw.as_ptr() as usize); // same storage
+    assert_eq!(bool8::as_u8_array(flags), &raw);
+
+    assert_eq!(bool8::into_u8_array(bool8::from_u8_array([0u8; 0])), [0u8; 0]);
+    assert_eq!(bool8::from_u8_array_ref(&[0u8; 0]).len(), 0);
+    assert_eq!(bool8::as_u8_array(&[bool8::FALSE; 0]), &[0u8; 0]);
+
+    let raw = [0u32, 1, 2, 0x100, u32::MAX, 0, 1, 0x8000_0000];
+    let flags = bool32::from_u32_array(raw);
+    assert_eq!(flags.map(bool::from), [false, true, true, true, true, false, true, true]);
+    assert_eq!(bool32::into_u32_array(flags), raw);
+    assert_eq!(bool32::as_u32_array(bool32::from_u32_array_ref(&raw)), &raw);
+
+    let raw = [-1, 0, 2, i32::MIN, 1, 0, 0, 1];
+    assert_eq!(bool32::into_i32_array(bool32::from_i32_array(raw)), raw);
+    assert_eq!(bool32::as_i32_array(bool32::from_i32_array_ref(&raw)), &raw);
+
+    assert_eq!(bool32::into_u32_array(bool32::from_u32_array([0u32; 0])), [0u32; 0]);
+    assert_eq!(bool32::as_i32_array(bool32::from_i32_array_ref(&[0i32; 0])), &[0i32; 0]);
+}
+
+// Run under miri (see the CI workflow) - these are all `unsafe` casts.
+#[test] fn slice_casts() {
+    let mut raw = [0u8, 1, 0x80, 0xff, 2]; // odd length
+    let flags = bool8::from_u8_slice(&raw);
+    assert_eq!(flags, [false, true, true, true, true]);
+    assert_eq!(flags.as_ptr() as usize, raw.as_ptr() as usize); // same storage
+    assert_eq!(bool8::as_u8_slice(flags), raw); // raw bits preserved
+
+    let flags = bool8::from_u8_slice_mut(&mut raw[1..4]); // unaligned, odd length sub-slice
+    flags[0] = bool8::FALSE;
+    flags[2].normalize();
+    assert_eq!(raw, [0, 0, 0x80, 1, 2]);
+
+    let mut flags = [bool8::TRUE, bool8::FALSE, bool8::from_raw(0x40)];
+    bool8::as_u8_slice_mut(&mut flags)[1] = 0x20;
+    assert!(bool8::raw_eq_bytes(&flags, &[1, 0x20, 0x40]));
+
+    assert!(bool8::from_u8_slice(&[]).is_empty());
+    assert!(bool8::from_u8_slice_mut(&mut []).is_empty());
+    assert!(bool8::as_u8_slice(&[]).is_empty());
+    assert!(bool8::as_u8_slice_mut(&mut []).is_empty());
+
+    let mut raw = [0i32, -1, 0x100];
+    let flags = bool32::from_i32_slice(&raw);
+    assert_eq!(flags, [false, true, true]);
+    assert_eq!(bool32::as_i32_slice(flags), raw);
+    bool32::from_i32_slice_mut(&mut raw)[1].toggle();
+    assert_eq!(raw, [0, 0, 0x100]);
+    let mut flags = [bool32::FALSE; 3];
+    bool32::as_i32_slice_mut(&mut flags)[2] = i32::MIN;
+    assert!(bool32::raw_eq_i32s(&flags, &[0, 0, i32::MIN]));
+
+    let mut raw = [u32::MAX, 0, 1];
+    let flags = bool32::from_u32_slice(&raw);
+    assert!(bool32::raw_eq_i32s(flags, &[-1, 0, 1]));
+    assert_eq!(bool32::as_u32_slice(flags), raw);
+    bool32::from_u32_slice_mut(&mut raw[..1])[0].normalize();
+    assert_eq!(raw, [1, 0, 1]);
+    let mut flags = [bool32::TRUE; 3];
+    bool32::as_u32_slice_mut(&mut flags)[0] = 0x8000_0000;
+    assert!(bool32::raw_eq_i32s(&flags, &[i32::MIN, 1, 1]));
+
+    assert!(bool32::from_i32_slice(&[]).is_empty());
+    assert!(bool32::from_i32_slice_mut(&mut []).is_empty());
+    assert!(bool32::as_i32_slice(&[]).is_empty());
+    assert!(bool32::as_i32_slice_mut(&mut []).is_empty());
+    assert!(bool32::from_u32_slice(&[]).is_empty());
+    assert!(bool32::from_u32_slice_mut(&mut []).is_empty());
+    assert!(bool32::as_u32_slice(&[]).is_empty());
+    assert!(bool32::as_u32_slice_mut(&mut []).is_empty());
+}
+
+#[test] fn tri_bool_resolve() {
+    for &default in &[false, true] {
+        assert_eq!(TriBool::True .resolve(default).into_raw(), 1);
+        assert_eq!(TriBool::False.resolve(default).into_raw(), 0);
+        assert_eq!(TriBool::Unset.resolve(default).into_raw(), default as u8);
+    }
+    assert_eq!(TriBool::default(), TriBool::Unset);
+
+    assert_eq!(TriBool::from(Some(bool8::TRUE)), TriBool::True);
+    assert_eq!(TriBool::from(Some(bool8::FALSE)), TriBool::False);
+    assert_eq!(TriBool::from(None), TriBool::Unset);
+    assert_eq!(TriBool::from(Some(bool8::from_raw(0x80))), TriBool::True); // by truthiness
+
+    for &tri in &[TriBool::True, TriBool::False, TriBool::Unset] {
+        assert_eq!(TriBool::from(Option::<bool8>::from(tri)), tri);
+    }
+    let canonical = Option::<bool8>::from(TriBool::from(Some(bool8::from_raw(0xff))));
+    assert_eq!(canonical.map(bool8::into_raw), Some(1)); // canonicalized by the round trip
+    assert_eq!(Option::<bool8>::from(TriBool::False).map(bool8::into_raw), Some(0));
+    assert_eq!(Option::<bool8>::from(TriBool::Unset), None);
+}
+
+#[test] fn tri_bool_kleene() {
+    use TriBool::{False as F, True as T, Unset as U};
+    let values = [T, F, U];
+
+    // Rows are `self`, columns are `other`, both in `values` order.
+    let and = [
+        [T, F, U],
+        [F, F, F],
+        [U, F, U],
+    ];
+    let or = [
+        [T, T, T],
+        [T, F, U],
+        [T, U, U],
+    ];
+    for (i, &a) in values.iter().enumerate() {
+        for (j, &b) in values.iter().enumerate() {
+            assert_eq!(a.and(b), and[i][j], "{:?} and {:?}", a, b);
+            assert_eq!(a.or (b), or [i][j], "{:?} or {:?}",  a, b);
+        }
+    }
+
+    assert_eq!(!T, F);
+    assert_eq!(!F, T);
+    assert_eq!(!U, U);
+}
+
+#[test] fn tri_bool_hash_map_key() {
+    use std::collections::HashMap;
+
+    let mut layers = HashMap::new();
+    layers.insert(TriBool::True, "global");
+    layers.insert(TriBool::Unset, "user");
+    assert_eq!(layers.insert(TriBool::from(Some(bool8::from_raw(2))), "system"), Some("global")); // same key as `True`
+    assert_eq!(layers.len(), 2);
+    assert_eq!(layers.get(&TriBool::True), Some(&"system"));
+    assert_eq!(layers.get(&TriBool::Unset), Some(&"user"));
+    assert_eq!(layers.get(&TriBool::False), None);
+
+    assert_eq!(TriBool::True, TriBool::True);
+    assert_ne!(TriBool::True, TriBool::False);
+    assert_ne!(TriBool::Unset, TriBool::False);
+    assert_eq!(TriBool::Unset.and(TriBool::True), TriBool::Unset);
+}
+
+#[test] fn not() {
+    fn check<T: core::ops::Not<Output = bool> + Copy + Debug + Into<bool>>(values: &[T]) {
+        for &v in values { assert_eq!(!v, !v.into(), "!{:?}", v); }
+    }
+    check(&B8); check(&B16); check(&B32); check(&B64);
+    check(&[bool8::from_raw(0xff), bool8::from_raw(2)]);
+    check(&[bool32::from_raw(-1), bool32::from_raw(0x100)]);
+    assert_eq!(B8 .map(|b| !b), [true, false, false]);
+    assert_eq!(B16.map(|b| !b), [true, false, false]);
+    assert_eq!(B32.map(|b| !b), [true, false, false]);
+    assert_eq!(B64.map(|b| !b), [true, false, false]); // `1 << 32` isn't truncated to a falsey `0`
+
+    assert_eq!([TriBool::True, TriBool::False, TriBool::Unset].map(|t| !t), [TriBool::False, TriBool::True, TriBool::Unset]);
+    assert_eq!(!TriBool::from(Some(bool8::from_raw(0x80))), TriBool::False);
+    assert_eq!(!!TriBool::Unset, TriBool::Unset);
+}
+
+#[test] fn const_combinators() {
+    const A : bool8 = bool8::from_raw(0x80);
+    const B : bool8 = bool8::from_raw(0x40);
+    const Z : bool8 = bool8::FALSE;
+    const TABLE : [bool8; 6] = [A.and_c(B), A.and_c(Z), Z.and_c(A), A.or_c(B), A.or_c(Z), Z.or_c(Z)];
+    assert!(bool8::raw_eq_bytes(&TABLE, &[1, 0, 0, 1, 1, 0])); // canonical - not `0x80 & 0x40` or `0x80 | 0x40`
+
+    const WIDE : [bool32; 2] = [bool32::from_raw(-1).and_c(bool32::from_raw(0x100)), bool32::FALSE.or_c(bool32::from_raw(i32::MIN))];
+    assert!(bool32::raw_eq_i32s(&WIDE, &[1, 1]));
+
+    const fn mode(enabled: bool8, verbose: bool32) -> u8 {
+        if enabled.is_true() {
+            if verbose.is_false() { 1 } else { 2 }
+        } else {
+            0
+        }
+    }
+    const MODES : [u8; 3] = [mode(Z, bool32::TRUE), mode(A, bool32::FALSE), mode(A, bool32::from_raw(-1))];
+    assert_eq!(MODES, [0, 1, 2]);
+    const _ : () = assert!(mode(B, bool32::from_raw(0x100)) == 2);
+}
+
+#[test] fn const_eq_widths() {
+    const _ : () = assert!( bool8 ::from_raw(0x80).eq_bool8 (bool8 ::TRUE));
+    const _ : () = assert!( bool8 ::from_raw(0x80).eq_bool16(bool16::from_raw(0x100)));
+    const _ : () = assert!( bool8 ::from_raw(0xff).eq_bool32(bool32::from_raw(i32::MIN)));
+    const _ : () = assert!( bool8 ::FALSE         .eq_bool64(bool64::FALSE));
+    const _ : () = assert!(!bool8 ::from_raw(0x80).eq_bool64(bool64::FALSE));
+    const _ : () = assert!( bool16::from_raw(0x100).eq_bool8 (bool8 ::from_raw(2)));
+    const _ : () = assert!(!bool16::FALSE          .eq_bool32(bool32::from_raw(-1)));
+    const _ : () = assert!( bool32::from_raw(-1)   .eq_bool64(bool64::from_raw(1 << 32)));
+    const _ : () = assert!(!bool32::from_raw(0x100).eq_bool8 (bool8 ::FALSE)); // truthiness of the whole word, not the low byte
+    const _ : () = assert!( bool64::from_raw(1 << 32).eq_bool16(bool16::TRUE));
+    const _ : () = assert!(!bool64::from_raw(1 << 32).eq_bool32(bool32::FALSE));
+
+    // A mixed-width constant table, checked at compile time.
+    const WIDE   : [bool32; 3] = [bool32::FALSE, bool32::from_raw(-1), bool32::TRUE];
+    const NARROW : [bool8 ; 3] = [bool8::FALSE, bool8::TRUE, bool8::from_raw(0xff)];
+    const _ : () = {
+        let mut i = 0;
+        while i < 3 { assert!(NARROW[i].eq_bool32(WIDE[i])); i += 1; }
+    };
+
+    // Runtime results match too.
+    for &a in &B8  { for &b in &B64 { assert_eq!(a.eq_bool64(b), a == b); } }
+    for &a in &B64 { for &b in &B16 { assert_eq!(a.eq_bool16(b), a == b); } }
+}
+
+#[cfg(target_has_atomic = "8")] #[test] fn atomic_bool8() {
+    use core::sync::atomic::Ordering::SeqCst;
+
+    let flag = AtomicBool8::new(false);
+    assert!(!flag.swap(true, SeqCst)); // previous truthiness
+    assert!(flag.swap(true, SeqCst));
+    assert_eq!(flag.compare_exchange(false, false, SeqCst, SeqCst), Err(true));
+    assert_eq!(flag.compare_exchange(true, false, SeqCst, SeqCst), Ok(true));
+    assert!(!flag.load(SeqCst));
+    flag.store(true, SeqCst);
+    assert_eq!(flag.into_inner().into_raw(), 1);
+    assert_eq!(AtomicBool8::default().into_inner().into_raw(), 0);
+
+    // e.g. a byte of shared memory written by a driver
+    let mut shared = 0x80u8;
+    let flag = unsafe { &*(&mut shared as *mut u8 as *const AtomicBool8) }; // #[repr(transparent)] over AtomicU8
+    assert!(flag.load(SeqCst));
+    assert_eq!(flag.compare_exchange(false, true, SeqCst, SeqCst), Err(true)); // `0x80` isn't falsey...
+    assert_eq!(flag.compare_exchange(true, false, SeqCst, SeqCst), Ok(true));  // ...and matches `true`, not just `1`
+    assert_eq!(shared, 0);
+
+    shared = 0xff;
+    let flag = unsafe { &*(&mut shared as *mut u8 as *const AtomicBool8) };
+    assert!(flag.swap(false, SeqCst));
+    assert_eq!(shared, 0);
+}
+
+#[cfg(target_has_atomic = "32")] #[test] fn atomic_bool32() {
+    use core::sync::atomic::Ordering::SeqCst;
+
+    let flag = AtomicBool32::new(true);
+    assert!(flag.swap(false, SeqCst)); // previous truthiness
+    assert!(!flag.swap(false, SeqCst));
+    assert_eq!(flag.compare_exchange(true, true, SeqCst, SeqCst), Err(false));
+    assert_eq!(flag.compare_exchange(false, true, SeqCst, SeqCst), Ok(false));
+    assert!(flag.load(SeqCst));
+    assert_eq!(flag.into_inner().into_raw(), 1);
+    assert_eq!(AtomicBool32::default().into_inner().into_raw(), 0);
+
+    let mut shared = -1i32;
+    let flag = unsafe { &*(&mut shared as *mut i32 as *const AtomicBool32) }; // #[repr(transparent)] over AtomicI32
+    assert_eq!(flag.compare_exchange(false, true, SeqCst, SeqCst), Err(true));
+    assert_eq!(flag.compare_exchange(true, true, SeqCst, SeqCst), Ok(true));
+    assert_eq!(shared, 1); // canonicalized
+
+    shared = i32::MIN;
+    let flag = unsafe { &*(&mut shared as *mut i32 as *const AtomicBool32) };
+    assert!(flag.load(SeqCst));
+    assert!(flag.swap(false, SeqCst));
+    flag.store(true, SeqCst);
+    assert_eq!(shared, 1);
+}
+
+#[test] fn as_widths() {
+    // Every width to every other width (and itself), canonicalizing - including the non-canonical `B*[2]`s, which a truncating conversion would get wrong.
+    assert_eq!(B8 .map(bool8 ::as_bool8 ).map(bool8 ::into_raw), [0, 1, 1]);
+    assert_eq!(B8 .map(bool8 ::as_bool16).map(bool16::into_raw), [0, 1, 1]);
+    assert_eq!(B8 .map(bool8 ::as_bool32).map(bool32::into_raw), [0, 1, 1]);
+    assert_eq!(B8 .map(bool8 ::as_bool64).map(bool64::into_raw), [0, 1, 1]);
+
+    assert_eq!(B16.map(bool16::as_bool8 ).map(bool8 ::into_raw), [0, 1, 1]);
+    assert_eq!(B16.map(bool16::as_bool16).map(bool16::into_raw), [0, 1, 1]);
+    assert_eq!(B16.map(bool16::as_bool32).map(bool32::into_raw), [0, 1, 1]);
+    assert_eq!(B16.map(bool16::as_bool64).map(bool64::into_raw), [0, 1, 1]);
+
+    assert_eq!(B32.map(bool32::as_bool8 ).map(bool8 ::into_raw), [0, 1, 1]);
+    assert_eq!(B32.map(bool32::as_bool16).map(bool16::into_raw), [0, 1, 1]);
+    assert_eq!(B32.map(bool32::as_bool32).map(bool32::into_raw), [0, 1, 1]);
+    assert_eq!(B32.map(bool32::as_bool64).map(bool64::into_raw), [0, 1, 1]);
+
+    assert_eq!(B64.map(bool64::as_bool8 ).map(bool8 ::into_raw), [0, 1, 1]);
+    assert_eq!(B64.map(bool64::as_bool16).map(bool16::into_raw), [0, 1, 1]);
+    assert_eq!(B64.map(bool64::as_bool32).map(bool32::into_raw), [0, 1, 1]);
+    assert_eq!(B64.map(bool64::as_bool64).map(bool64::into_raw), [0, 1, 1]);
+}
+
+crate::define_abibool! {
+    /// Signed 8-bit boolean, defined the way a downstream crate would - not via `@impl`.
+    pub struct sbool8(i8);
+}
+
+#[test] fn define_abibool_user_type() {
+    let (f, t, n) = (sbool8::FALSE, sbool8::TRUE, sbool8::from_raw(-1));
+    assert_eq!(core::mem::size_of::<sbool8>(), 1);
+
+    assert_eq!(n, t);
+    assert!(bool::from(n));
+    assert_ne!(n, f);
+    assert_eq!(n.into_raw(), -1);
+
+    assert!(f < n);
+    assert!(n > f);
+    assert!(n <= t);
+    assert!(n >= t);
+    assert_eq!(n.cmp(&t), Ordering::Equal);
+    assert_eq!(n.partial_cmp(&t), Some(Ordering::Equal));
+    assert_eq!(n.cmp_raw(t), Ordering::Less);
+
+    assert_eq!((n & sbool8::from_raw(0x40)).into_raw(), 1);
+    assert_eq!((n | f).into_raw(), 1);
+    assert_eq!((n ^ t).into_raw(), 0);
+    assert_eq!((n & false).into_raw(), 0);
+    let mut flag = n;
+    flag |= true;
+    assert_eq!(flag.into_raw(), 1);
+    assert_eq!(!n, f);
+    assert_eq!(n.toggled().into_raw(), 0);
+
+    assert_eq!("1"    .parse::<sbool8>().map(sbool8::into_raw), Ok(1));
+    assert_eq!("false".parse::<sbool8>().map(sbool8::into_raw), Ok(0));
+    assert!("-1".parse::<sbool8>().is_err());
+
+    assert_eq!(sbool8::try_from_raw(0).map(sbool8::into_raw), Ok(0));
+    assert_eq!(sbool8::try_from_raw(1).map(sbool8::into_raw), Ok(1));
+    assert_eq!(sbool8::try_from_raw(-1).map(sbool8::into_raw), Err(NonCanonicalError(-1)));
+
+    assert_eq!(n.as_bool8().into_raw(), 1);
+    assert!(n.eq_bool32(bool32::from_raw(-1)));
+}
+
+#[cfg(feature = "serde")] mod _serde {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::string::String;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        a: bool8,
+        #[serde(default)] b: bool32,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Outer {
+        name: String,
+        #[serde(flatten)] config: Config,
+        #[serde(flatten)] extra: BTreeMap<String, bool8>,
+    }
+
+    #[test] fn plain_bools() {
+        assert_eq!(serde_json::to_string(&bool8::from_raw(0xff)).unwrap(), "true");
+        assert_eq!(serde_json::to_string(&bool32::FALSE).unwrap(), "false");
+        assert_eq!(serde_json::from_str::<bool8>("true").unwrap().into_raw(), 1);
+        assert_eq!(serde_json::from_str::<bool32>("false").unwrap().into_raw(), 0);
+        assert!(serde_json::from_str::<bool8>("1").is_err());
+        assert!(serde_json::from_str::<bool32>("\"true\"").is_err());
+
+        assert_eq!(serde_json::to_string(&[bool16::from_raw(0x100), bool16::FALSE]).unwrap(), "[true,false]");
+        assert_eq!(serde_json::to_string(&bool64::from_raw(1 << 63)).unwrap(), "true");
+        assert_eq!(serde_json::from_str::<bool16>("true").unwrap().into_raw(), 1);
+        assert_eq!(serde_json::from_str::<bool64>("true").unwrap().into_raw(), 1);
+        assert!(serde_json::from_str::<bool64>("0").is_err());
+    }
+
+    #[test] fn default() {
+        let config : Config = serde_json::from_str(r#"{"a":true}"#).unwrap();
+        assert_eq!(config, Config { a: bool8::TRUE, b: bool32::FALSE });
+        assert_eq!(config.b.into_raw(), 0);
+        assert!(serde_json::from_str::<Config>(r#"{"b":true}"#).is_err()); // `a` has no default
+    }
+
+    #[test] fn flatten() {
+        let outer : Outer = serde_json::from_str(r#"{"name":"caps","a":true,"b":false,"x":true,"y":false}"#).unwrap();
+        assert_eq!(outer.config, Config { a: bool8::TRUE, b: bool32::FALSE });
+        assert_eq!(outer.extra.get("x").copied(), Some(bool8::TRUE));
+        assert_eq!(outer.extra.get("y").copied(), Some(bool8::FALSE));
+        assert_eq!(serde_json::to_string(&outer).unwrap(), r#"{"name":"caps","a":true,"b":false,"x":true,"y":false}"#);
+
+        let outer : Outer = serde_json::from_str(r#"{"name":"caps","a":false}"#).unwrap();
+        assert_eq!(outer.config, Config::default());
+        assert!(serde_json::from_str::<Outer>(r#"{"name":"caps","a":1}"#).is_err());
+    }
+}
+
+#[test] fn hash_map_borrow_bool() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut map = HashMap::new();
+    map.insert(bool8::from_raw(0xff), "on");
+    map.insert(bool8::FALSE, "off");
+    assert_eq!(map.get(&true), Some(&"on"));
+    assert_eq!(map.get(&false), Some(&"off"));
+    assert_eq!(map.get(&bool8::TRUE), Some(&"on"));
+    assert_eq!(map.insert(bool8::from_raw(2), "also on"), Some("on")); // same key by truthiness
+    assert_eq!(map.len(), 2);
+
+    let mut map = HashMap::new();
+    map.insert(bool32::from_raw(-1), 1);
+    assert_eq!(map.get(&true), Some(&1));
+    assert_eq!(map.get(&false), None);
+
+    let mut map = BTreeMap::new();
+    map.insert(bool64::from_raw(1 << 40), 'x');
+    assert_eq!(map.get(&true), Some(&'x'));
+
+    // `HashMap<bool, V>` can't be queried with `&bool8` - convert the key instead.
+    let map : HashMap<bool, i32> = [(true, 1)].iter().copied().collect();
+    assert_eq!(map.get(&bool::from(bool16::from_raw(0x100))), Some(&1));
+}
+
+#[cfg(feature = "arbitrary")] mod _arbitrary {
+    use super::*;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test] fn raw_values_preserved() {
+        let mut u = Unstructured::new(&[0x00, 0x01, 0x80, 0xff]);
+        let flags = [(); 4].map(|_| bool8::arbitrary(&mut u).unwrap().into_raw());
+        assert_eq!(flags, [0x00, 0x01, 0x80, 0xff]); // non-canonical values aren't normalized away
+
+        assert_eq!(bool16::arbitrary(&mut Unstructured::new(&[0xff; 2])).unwrap().into_raw(), u16::MAX);
+        assert_eq!(bool32::arbitrary(&mut Unstructured::new(&[0xff; 4])).unwrap().into_raw(), -1);
+        assert_eq!(bool64::arbitrary(&mut Unstructured::new(&[0xff; 8])).unwrap().into_raw(), u64::MAX);
+        assert_eq!(bool32::arbitrary(&mut Unstructured::new(&[0x00; 4])).unwrap().into_raw(), 0);
+
+        let bytes = [0x00, 0x01, 0x00, 0x80, 0x12, 0x34, 0x56, 0x78];
+        assert_eq!(bool16::arbitrary(&mut Unstructured::new(&bytes)).unwrap().into_raw(), u16::arbitrary(&mut Unstructured::new(&bytes)).unwrap());
+        assert_eq!(bool32::arbitrary(&mut Unstructured::new(&bytes)).unwrap().into_raw(), i32::arbitrary(&mut Unstructured::new(&bytes)).unwrap());
+        assert_eq!(bool64::arbitrary(&mut Unstructured::new(&bytes)).unwrap().into_raw(), u64::arbitrary(&mut Unstructured::new(&bytes)).unwrap());
+        assert_eq!(bool32::size_hint(0), i32::size_hint(0));
+    }
+}
+
+#[cfg(feature = "defmt")] mod _defmt {
+    use super::*;
+
+    // Compile-only: actually formatting needs a defmt logger and target, which host tests don't have.
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test] fn format() {
+        assert_format::<bool8 >();
+        assert_format::<bool16>();
+        assert_format::<bool32>();
+        assert_format::<bool64>();
+        assert_format::<[bool8; 4]>();
+    }
+}
+
+#[cfg(feature = "zerocopy")] mod _zerocopy {
+    use super::*;
+    use zerocopy::{FromBytes, FromZeros, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+    #[derive(FromBytes, IntoBytes, Immutable, KnownLayout)]
+    #[repr(C)] struct Caps { a: bool8, b: bool8, c: bool16, d: bool32, e: bool64 }
+
+    #[derive(FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned)]
+    #[repr(C)] struct Flags { tag: u8, flags: [bool8; 3] } // Unaligned requires every field to be
+
+    #[derive(FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned)]
+    #[repr(C, packed)] struct Packed { tag: u8, flag: bool8, word: bool32 }
+
+    #[test] fn derive_from_bytes() {
+        let mut bytes = [0u8; 16];
+        bytes[0] = 0xff;
+        bytes[2] = 2;
+        bytes[4..8].copy_from_slice(&(-1i32).to_ne_bytes());
+        let caps = Caps::read_from_bytes(&bytes[..]).unwrap();
+        assert_eq!((caps.a.into_raw(), caps.b.into_raw(), caps.c.into_raw(), caps.d.into_raw(), caps.e.into_raw()), (0xff, 0, 2, -1, 0));
+        assert_eq!(caps.as_bytes(), &bytes[..]);
+        assert_eq!(bool64::new_zeroed(), bool64::FALSE);
+    }
+
+    #[test] fn unaligned() {
+        let buffer = [0u8, 7, 0, 0x80, 1];
+        let flags = Flags::ref_from_bytes(&buffer[1..]).unwrap(); // any offset is fine
+        assert_eq!(flags.tag, 7);
+        assert_eq!(flags.flags, [false, true, true]);
+
+        let mut buffer = [0u8; 7];
+        buffer[2] = 0x40;
+        buffer[3..7].copy_from_slice(&0x100i32.to_ne_bytes());
+        let packed = Packed::ref_from_bytes(&buffer[1..]).unwrap();
+        let (tag, flag, word) = (packed.tag, packed.flag, packed.word); // copy out of the packed struct
+        assert_eq!((tag, flag.into_raw(), word.into_raw()), (0, 0x40, 0x100));
+        assert_eq!(packed.as_bytes(), &buffer[1..]);
+    }
+}
+
+#[cfg(feature = "rkyv")] mod _rkyv {
+    use super::*;
+    use rkyv::rancor::Error;
+
+    #[derive(Debug, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+    struct Caps { a: bool8, b: bool16, c: bool32, d: bool64 }
+
+    #[test] fn archive_access_deserialize() {
+        let caps = Caps { a: bool8::from_raw(0xff), b: bool16::FALSE, c: bool32::from_raw(-1), d: bool64::TRUE };
+        let bytes = rkyv::to_bytes::<Error>(&caps).unwrap();
+        assert_eq!(bytes.len(), 4); // every width archives as a single byte
+
+        let archived = rkyv::access::<ArchivedCaps, Error>(&bytes).unwrap();
+        let _ : &bool8 = &archived.c;
+        assert_eq!(archived.a.into_raw(), 0xff); // bool8 is archived as-is
+        assert_eq!((archived.b.into_raw(), archived.c.into_raw(), archived.d.into_raw()), (0, 1, 1));
+
+        let caps = rkyv::deserialize::<Caps, Error>(archived).unwrap();
+        assert_eq!((caps.a.into_raw(), caps.b.into_raw(), caps.c.into_raw(), caps.d.into_raw()), (0xff, 0, 1, 1));
+
+        let archived = rkyv::access::<ArchivedCaps, Error>(&[0x80, 0, 2, 0][..]).unwrap(); // any byte is a valid bool8
+        assert_eq!(rkyv::deserialize::<Caps, Error>(archived).unwrap().c.into_raw(), 1);
+    }
+}
+
+#[cfg(feature = "borsh")] mod _borsh {
+    use super::*;
+
+    #[test] fn round_trip() {
+        assert_eq!(borsh::to_vec(&bool8::from_raw(0xff)).unwrap(), [1]);
+        assert_eq!(borsh::to_vec(&bool16::FALSE).unwrap(), [0]);
+        assert_eq!(borsh::to_vec(&bool32::from_raw(-1)).unwrap(), [1]);
+        assert_eq!(borsh::to_vec(&bool64::from_raw(1 << 40)).unwrap(), [1]);
+        assert_eq!(borsh::to_vec(&(bool8::TRUE, bool32::FALSE)).unwrap(), [1, 0]);
+
+        assert_eq!(borsh::from_slice::<bool8 >(&[1]).unwrap().into_raw(), 1);
+        assert_eq!(borsh::from_slice::<bool16>(&[0]).unwrap().into_raw(), 0);
+        assert_eq!(borsh::from_slice::<bool32>(&[1]).unwrap().into_raw(), 1);
+        assert_eq!(borsh::from_slice::<bool64>(&borsh::to_vec(&bool64::TRUE).unwrap()).unwrap().into_raw(), 1);
+    }
+
+    #[test] fn non_canonical_input() {
+        // Unlike borsh's own `bool`, which rejects anything but `0` or `1`.
+        assert!(borsh::from_slice::<bool>(&[0xff]).is_err());
+        assert_eq!(borsh::from_slice::<bool8 >(&[0xff]).unwrap().into_raw(), 1);
+        assert_eq!(borsh::from_slice::<bool32>(&[2]).unwrap().into_raw(), 1);
+        assert!(borsh::from_slice::<bool32>(&[]).is_err());
+        assert!(borsh::from_slice::<bool8>(&[1, 1]).is_err()); // trailing bytes
+    }
+}
+
+#[cfg(feature = "schemars")] mod _schemars {
+    use super::*;
+    use schemars::{schema_for, JsonSchema, SchemaGenerator};
+
+    #[test] fn boolean_schema() {
+        fn check<T: JsonSchema>() {
+            assert_eq!(schema_for!(T).get("type"), schema_for!(bool).get("type"));
+            assert_eq!(schema_for!(T).get("type").and_then(|t| t.as_str()), Some("boolean"));
+            assert_eq!(SchemaGenerator::default().subschema_for::<T>(), SchemaGenerator::default().subschema_for::<bool>()); // inlined, no `$ref`
+        }
+        check::<bool8>(); check::<bool16>(); check::<bool32>(); check::<bool64>();
+    }
+}
+
+#[cfg(feature = "windows-result")] mod _windows_result {
+    use super::*;
+    use windows_result::BOOL;
+
+    #[test] fn round_trip() {
+        for raw in [0, 1, -1] {
+            let flag = bool32::from(BOOL(raw));
+            assert_eq!(flag.into_raw(), raw); // as-is, not normalized
+            assert_eq!(BOOL::from(flag).0, raw);
+        }
+        assert!(bool32::from(BOOL(-1)).is_true());
+        assert!(bool32::from(BOOL(0)).is_false());
+    }
+}
+
+#[test] fn collect_array() {
+    assert_eq!(bool8::collect_array::<3>([true, false, true]).map(bool8::into_u8_array), Ok([1, 0, 1]));
+    assert_eq!(bool32::collect_array::<2>([false, true]).map(bool32::into_i32_array), Ok([0, 1]));
+    assert_eq!(bool8::collect_array::<0>([]).map(bool8::into_u8_array), Ok([]));
+
+    // short
+    assert_eq!(bool8::collect_array::<3>([]), Err(0));
+    assert_eq!(bool8::collect_array::<3>([true, true]), Err(2));
+
+    // long
+    assert_eq!(bool8::collect_array::<3>([true; 4]), Err(4));
+    assert_eq!(bool8::collect_array::<3>([true; 100]), Err(4));
+    assert_eq!(bool8::collect_array::<0>([false]), Err(1));
+    assert_eq!(bool64::collect_array::<2>(core::iter::repeat(false)), Err(3)); // doesn't drain unbounded iterators
+
+    let mut iter = [true, false, true, false].iter().copied();
+    assert_eq!(bool16::collect_array::<2>(&mut iter), Err(3));
+    assert_eq!(iter.next(), Some(false)); // only consumed one past `N`
+}
+
+#[cfg(feature = "proptest")] mod _proptest {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    proptest! {
+        #[test] fn eq_is_truthiness(a in any_bool8(), b in any::<bool8>(), c in any_bool32(), d in any::<bool32>()) {
+            prop_assert_eq!(a == b, bool::from(a) == bool::from(b));
+            prop_assert_eq!(c == d, bool::from(c) == bool::from(d));
+            prop_assert_eq!(a == c, bool::from(a) == bool::from(c));
+        }
+
+        #[test] fn normalized_preserves_truthiness(a in any_bool16(), b in any_bool64()) {
+            prop_assert_eq!(a.normalized(), a);
+            prop_assert!(a.normalized().into_raw() <= 1);
+            prop_assert_eq!(b.normalized(), b);
+            prop_assert!(b.normalized().into_raw() <= 1);
+        }
+    }
+
+    fn samples<S: Strategy>(strategy: S) -> Vec<S::Value> {
+        let mut runner = TestRunner::deterministic();
+        (0..256).map(|_| strategy.new_tree(&mut runner).unwrap().current()).collect()
+    }
+
+    #[test] fn samples_non_canonical() {
+        // Not just `0`/`1` - the full integer range, including negative `bool32`s.
+        assert!(samples(any_bool8 ()).iter().any(|b| b.into_raw() > 1));
+        assert!(samples(any_bool16()).iter().any(|b| b.into_raw() > 1));
+        assert!(samples(any_bool32()).iter().any(|b| b.into_raw() < 0));
+        assert!(samples(any_bool64()).iter().any(|b| b.into_raw() > 1));
+    }
+}
+
+#[cfg(feature = "alloc")] mod _alloc {
+    use super::*;
+
+    #[test] fn vec_reinterpret() {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.extend_from_slice(&[0u8, 1, 0x80, 0xff]);
+        let (ptr, cap) = (bytes.as_ptr() as usize, bytes.capacity());
+
+        let mut flags = bool8::vec_from_u8(bytes);
+        assert_eq!((flags.as_ptr() as usize, flags.capacity()), (ptr, cap)); // no reallocation
+        assert_eq!(flags, [false, true, true, true]);
+        flags.push(bool8::from_raw(0x40)); // within capacity
+        assert_eq!(flags.as_ptr() as usize, ptr);
+
+        let bytes = bool8::vec_into_u8(flags);
+        assert_eq!((bytes.as_ptr() as usize, bytes.capacity()), (ptr, cap));
+        assert_eq!(bytes, [0, 1, 0x80, 0xff, 0x40]); // raw bits preserved
+
+        let ints = [0, 1, -1, i32::MIN].to_vec();
+        let (ptr, cap) = (ints.as_ptr() as usize, ints.capacity());
+        let flags = bool32::vec_from_i32(ints);
+        assert_eq!((flags.as_ptr() as usize, flags.capacity()), (ptr, cap));
+        assert_eq!(flags, [false, true, true, true]);
+        assert_eq!(bool32::vec_into_i32(flags), [0, 1, -1, i32::MIN]);
+
+        assert!(bool8::vec_from_u8(Vec::new()).is_empty());
+        assert!(bool32::vec_into_i32(Vec::new()).is_empty());
+    }
+
+    #[test] fn collect_infers() {
+        // Enabling `alloc` mustn't add `FromIterator` impls that break `Vec<_>` inference elsewhere.
+        let flags = [bool8::from_raw(0x80), bool8::FALSE];
+        let v = flags.iter().copied().rev().collect::<Vec<_>>();
+        assert!(bool8::raw_eq_bytes(&v, &[0, 0x80]));
+        let v : Vec<_> = B32.iter().copied().rev().collect();
+        assert!(bool32::raw_eq_i32s(&v, &[i32::MIN, 1, 0]));
+
+        assert!(bool32::raw_eq_i32s(&bool32::collect_vec([true, false]), &[1, 0]));
+    }
+
+    #[test] fn extend_bools() {
+        let mut plain = Vec::new();
+        bool8::extend_bools(&mut plain, B8.iter().copied());
+        assert_eq!(plain, [false, true, true]);
+        bool32::extend_bools(&mut plain, B32.iter().copied());
+        bool32::extend_bools(&mut plain, [bool32::from_raw(-1)]);
+        bool8::extend_bools(&mut plain, core::iter::empty());
+        assert_eq!(plain, [false, true, true, false, true, true, true]);
+
+        let mut v = Vec::new();
+        v.extend(B8.iter().copied()); // still infers `Vec<bool8>` with `alloc` enabled
+        assert!(bool8::raw_eq_bytes(&v, &[0, 1, 0x80]));
+    }
+
+    #[test] fn decode_strict() {
+        assert_eq!(bool8::decode_strict(&[0, 1, 1, 0]), Ok([false, true, true, false].to_vec()));
+        assert_eq!(bool8::decode_strict(&[]), Ok(Vec::new()));
+        assert_eq!(bool8::decode_strict(&[0, 1, 0x80, 2]), Err((2, 0x80))); // first bad byte
+        assert_eq!(bool8::decode_strict(&[0xff]), Err((0, 0xff)));
+
+        assert_eq!(bool32::decode_strict(&[1, 0, 1]), Ok([true, false, true].to_vec()));
+        assert_eq!(bool32::decode_strict(&[1, 1, 1, -1]), Err((3, -1)));
+        assert_eq!(bool32::decode_strict(&[0x100]), Err((0, 0x100)));
+    }
+}
+
+#[cfg(feature = "heapless")] mod _heapless {
+    use super::*;
+
+    #[test] fn collect_heapless() {
+        let flags = bool8::collect_heapless::<4>([true, false, true]).unwrap();
+        assert!(bool8::raw_eq_bytes(&flags, &[1, 0, 1]));
+        assert_eq!(flags.capacity(), 4);
+
+        let full = bool32::collect_heapless::<3>([false, true, true]).unwrap();
+        assert!(bool32::raw_eq_i32s(&full, &[0, 1, 1]));
+        assert!(full.is_full());
+
+        assert_eq!(bool8::collect_heapless::<0>([]).map(|v| v.len()), Ok(0));
+
+        // overflow
+        assert_eq!(bool8::collect_heapless::<2>([true; 3]).unwrap_err(), 3);
+        assert_eq!(bool32::collect_heapless::<0>([false]).unwrap_err(), 1);
+        assert_eq!(bool8::collect_heapless::<2>(core::iter::repeat(true)).unwrap_err(), 3); // doesn't drain unbounded iterators
+
+        let mut iter = [true, false, true, false].iter().copied();
+        assert_eq!(bool32::collect_heapless::<2>(&mut iter).unwrap_err(), 3);
+        assert_eq!(iter.next(), Some(false)); // only consumed one past `N`
+    }
+}
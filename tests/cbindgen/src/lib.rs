@@ -0,0 +1,6 @@
+//! Fixture for checking the C header cbindgen generates for abibool's types.
+
+use abibool::*;
+
+#[no_mangle] pub extern "C" fn abibool_cbindgen_not8(value: bool8) -> bool8 { value.toggled() }
+#[no_mangle] pub extern "C" fn abibool_cbindgen_not32(value: bool32) -> bool32 { value.toggled() }
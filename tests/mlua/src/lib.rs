@@ -0,0 +1,31 @@
+//! Fixture for testing abibool's `mlua` feature, which needs a Lua version (here, a vendored Lua 5.4) to build.
+#![cfg(test)]
+
+use abibool::*;
+use mlua::{Lua, Value};
+
+#[test] fn round_trip() {
+    let lua = Lua::new();
+    let globals = lua.globals();
+    globals.set("a", bool8::from_raw(0xff)).unwrap();
+    globals.set("b", bool16::FALSE).unwrap();
+    globals.set("c", bool32::from_raw(-1)).unwrap();
+    globals.set("d", bool64::from_raw(1 << 40)).unwrap();
+    assert_eq!(lua.load("return type(a), a == true, b == false, c == true, d == true").eval::<(String, bool, bool, bool, bool)>().unwrap(), ("boolean".into(), true, true, true, true));
+
+    assert_eq!(globals.get::<bool8 >("a").unwrap().into_raw(), 1); // canonicalized on the way back
+    assert_eq!(globals.get::<bool16>("b").unwrap().into_raw(), 0);
+    assert_eq!(globals.get::<bool32>("c").unwrap().into_raw(), 1);
+    assert_eq!(globals.get::<bool64>("d").unwrap().into_raw(), 1);
+}
+
+#[test] fn lua_truthiness() {
+    // Only `nil` and `false` are falsey in Lua - numbers like `0` are truthy, unlike C.
+    let lua = Lua::new();
+    assert_eq!(lua.load("return 0"    ).eval::<bool8 >().unwrap().into_raw(), 1);
+    assert_eq!(lua.load("return ''"   ).eval::<bool32>().unwrap().into_raw(), 1);
+    assert_eq!(lua.load("return {}"   ).eval::<bool16>().unwrap().into_raw(), 1);
+    assert_eq!(lua.load("return nil"  ).eval::<bool64>().unwrap().into_raw(), 0);
+    assert_eq!(lua.load("return false").eval::<bool8 >().unwrap().into_raw(), 0);
+    assert!(matches!(mlua::IntoLua::into_lua(bool32::from_raw(0x100), &lua).unwrap(), Value::Boolean(true)));
+}